@@ -0,0 +1,48 @@
+//! Benchmarks of the Schrage algorithms for growing instances.
+//!
+//! Run with `cargo bench`. Throughput is reported in jobs per second.
+//! `c_max` and `baseline_sort` time the c max computation and the sort by
+//! delivery time on their own, to see how much of a Schrage run they take.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use proc_opt::gen::{generate_jobs, GenConfig};
+use proc_opt::schrage::jobs::JobList;
+use proc_opt::schrage::{schrage, schrage_preemptive};
+
+const SIZES: [usize; 4] = [10, 100, 1000, 10000];
+
+/// Instance of `n` jobs, generated with `n` as the seed.
+fn instance(n: usize) -> JobList {
+    let config = GenConfig {
+        r_max: 1000,
+        p_max: 100,
+        q_max: 1000,
+        r_min: 0,
+        p_min: 1,
+        q_min: 0,
+    };
+    JobList::new(generate_jobs(n, config, n as u64))
+}
+
+/// Runs `f` on an instance of every size in the group `name`.
+fn bench_group<T>(c: &mut Criterion, name: &str, f: impl Fn(&JobList) -> T) {
+    let mut group = c.benchmark_group(name);
+    for n in SIZES {
+        let jobs = instance(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &jobs, |b, jobs| {
+            b.iter(|| f(black_box(jobs)))
+        });
+    }
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench_group(c, "schrage", schrage);
+    bench_group(c, "schrage_preemptive", schrage_preemptive);
+    bench_group(c, "c_max", JobList::c_max);
+    bench_group(c, "baseline_sort", JobList::sorted_by_delivery_time);
+}
+
+criterion_group!(schrage_benches, benches);
+criterion_main!(schrage_benches);
@@ -0,0 +1,16 @@
+//! Reads arbitrary bytes as an instance file, every input must give
+//! either an error or jobs that are written and read back unchanged.
+//! Run with `cargo +nightly fuzz run fuzz_or_library` from the repository
+//! root.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use proc_opt::io::or_library::{read_or_library_instance, write_or_library_instance};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(jobs) = read_or_library_instance(data) {
+        let mut buffer = Vec::new();
+        write_or_library_instance(&jobs, &mut buffer).unwrap();
+        assert_eq!(read_or_library_instance(buffer.as_slice()).unwrap(), jobs);
+    }
+});
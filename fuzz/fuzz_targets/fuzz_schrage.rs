@@ -0,0 +1,41 @@
+//! Runs `schrage` and `schrage_preemptive` on arbitrary jobs.
+//!
+//! Every 6 bytes of input are one job, its delivery, processing and cooldown
+//! times read as `u16` and upcast to `u32`, so that no sum of them can
+//! overflow. Run with `cargo +nightly fuzz run fuzz_schrage` from the
+//! repository root.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use proc_opt::schrage::jobs::{Job, JobList};
+use proc_opt::schrage::{schrage, schrage_preemptive};
+
+/// Upper bound on the number of jobs, keeps every run fast.
+const MAX_JOBS: usize = 1024;
+
+fuzz_target!(|data: &[u8]| {
+    let jobs: Vec<Job> = data
+        .chunks_exact(6)
+        .take(MAX_JOBS)
+        .map(|chunk| {
+            let time = |i: usize| u16::from_le_bytes([chunk[i], chunk[i + 1]]) as u32;
+            Job::new(time(0), time(2), time(4))
+        })
+        .collect();
+    if jobs.is_empty() {
+        return;
+    }
+    let js = JobList::new(jobs);
+
+    let result = schrage(&js);
+    assert_eq!(result.job_list.jobs.len(), js.jobs.len());
+    let mut scheduled = result.job_list.jobs.clone();
+    let mut expected = js.jobs.clone();
+    scheduled.sort_by_key(|j| (j.delivery_time, j.processing_time, j.cooldown_time));
+    expected.sort_by_key(|j| (j.delivery_time, j.processing_time, j.cooldown_time));
+    assert_eq!(scheduled, expected);
+
+    let preemptive = schrage_preemptive(&js);
+    assert!(preemptive.is_feasible());
+    assert!(preemptive.c_max() <= result.c_max());
+});
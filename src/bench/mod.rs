@@ -0,0 +1,77 @@
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::{schrage, schrage_preemptive};
+use alloc::vec::Vec;
+
+/// Result of the Schrage algorithm on a single instance of a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchResult {
+    /// C max of the Schrage schedule.
+    pub schrage_c_max: u32,
+    /// C max of the preemptive Schrage schedule, a lower bound on the optimum.
+    pub preemptive_lb: u32,
+    /// `schrage_c_max - preemptive_lb`, 0 means Schrage is proven optimal.
+    pub gap: u32,
+}
+
+/// Runs the Schrage algorithm on every instance and compares it
+/// with the preemptive lower bound.
+///
+/// # Panics
+///
+/// Panics if any instance is empty.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::bench::batch_evaluate;
+/// let result = batch_evaluate(vec![vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]]);
+/// assert_eq!(result[0].gap, 0);
+/// ```
+pub fn batch_evaluate(instances: Vec<Vec<Job>>) -> Vec<BatchResult> {
+    instances
+        .into_iter()
+        .map(|jobs| {
+            let jobs = JobList::new(jobs);
+            let schrage_c_max = schrage(&jobs).c_max();
+            let preemptive_lb = schrage_preemptive(&jobs).c_max();
+            BatchResult {
+                schrage_c_max,
+                preemptive_lb,
+                gap: schrage_c_max - preemptive_lb,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_instances;
+
+    #[test]
+    fn test_batch_evaluate() {
+        // batch_evaluate panics on an empty instance
+        let instances: Vec<Vec<Job>> = test_instances()
+            .into_iter()
+            .filter(|jobs| !jobs.is_empty())
+            .collect();
+        let result = batch_evaluate(instances.clone());
+        assert_eq!(result.len(), instances.len());
+        // The 7 job example, where Schrage gives 53 instead of the optimal 50
+        assert_eq!(instances[0].len(), 7);
+        assert_eq!(result[0].schrage_c_max, 53);
+        assert!(result[0].gap > 0);
+        for (jobs, r) in instances.into_iter().zip(&result) {
+            let jobs = JobList::new(jobs);
+            assert_eq!(r.schrage_c_max, schrage(&jobs).c_max());
+            assert_eq!(r.preemptive_lb, schrage_preemptive(&jobs).c_max());
+            assert_eq!(r.gap, r.schrage_c_max - r.preemptive_lb);
+        }
+    }
+
+    #[test]
+    fn test_batch_evaluate_empty_batch() {
+        assert!(batch_evaluate(Vec::new()).is_empty());
+    }
+}
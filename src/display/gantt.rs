@@ -0,0 +1,283 @@
+use crate::schrage::jobs::JobSchedule;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Layout of a chart drawn by [`render_gantt_with_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GanttConfig {
+    /// Number of time units drawn as a single character.
+    pub scale: u32,
+    /// Largest number of characters of the timeline, the scale is raised
+    /// if the schedule does not fit.
+    pub max_width: usize,
+    /// Whether every row starts with the label of its job.
+    pub show_job_labels: bool,
+}
+
+impl Default for GanttConfig {
+    fn default() -> Self {
+        GanttConfig {
+            scale: 1,
+            max_width: 80,
+            show_job_labels: true,
+        }
+    }
+}
+
+/// Renders a text Gantt chart of a schedule with [`GanttConfig::default`].
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::display::gantt::render_gantt;
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::schrage_preemptive;
+/// let js = JobList::new(vec![Job::new(0, 4, 1), Job::new(2, 2, 9)]);
+/// let chart = render_gantt(&schrage_preemptive(&js));
+/// assert_eq!(chart, "J0 |##..##|\nJ1 |..##..|\n");
+/// ```
+pub fn render_gantt(schedule: &JobSchedule) -> String {
+    render_gantt_with_config(schedule, &GanttConfig::default())
+}
+
+/// Renders a text Gantt chart of a schedule.
+///
+/// Every job is a row, labeled `Jn` for the job at index `n` of
+/// `schedule.jobs`, and every character of a row is `config.scale` time
+/// units. A character is `#` if the job runs at any moment of them and `.`
+/// if it does not, so preempted jobs show every segment they run in. The
+/// timeline ends when the machine is done, cooldown times are not drawn.
+///
+/// # Panics
+///
+/// Panics if `config.scale` or `config.max_width` is 0.
+pub fn render_gantt_with_config(schedule: &JobSchedule, config: &GanttConfig) -> String {
+    render_labeled_gantt(schedule, &[], config)
+}
+
+/// Renders a text Gantt chart of a schedule like [`render_gantt_with_config`],
+/// with the row of the job at index `n` labeled `labels[n]` instead of `Jn`
+/// when there is one, e.g. the labels of [`LabeledJob`]s given by [`labels`].
+///
+/// # Panics
+///
+/// Panics if `config.scale` or `config.max_width` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::display::gantt::{render_labeled_gantt, GanttConfig};
+/// use proc_opt::schrage::jobs::{labels, JobList, LabeledJob};
+/// use proc_opt::schrage::schrage_preemptive;
+/// let jobs = vec![LabeledJob::new("Setup", 0, 4, 1), LabeledJob::new("Run", 2, 2, 9)];
+/// let schedule = schrage_preemptive(&jobs.iter().cloned().map(LabeledJob::into_job).collect());
+/// let chart = render_labeled_gantt(&schedule, &labels(&jobs), &GanttConfig::default());
+/// assert_eq!(chart, "Setup |##..##|\nRun   |..##..|\n");
+/// ```
+///
+/// [`LabeledJob`]: crate::schrage::jobs::LabeledJob
+/// [`labels`]: crate::schrage::jobs::labels
+pub fn render_labeled_gantt(
+    schedule: &JobSchedule,
+    labels: &[Option<&str>],
+    config: &GanttConfig,
+) -> String {
+    assert!(config.scale > 0, "the scale must be positive");
+    assert!(config.max_width > 0, "the width must be positive");
+    let end = schedule
+        .timetable
+        .iter()
+        .map(|&(start, _, duration)| start + duration)
+        .max()
+        .unwrap_or(0);
+    let width = config.max_width as u32;
+    let scale = config.scale.max(end.div_ceil(width));
+    let columns = end.div_ceil(scale) as usize;
+    let row_labels: Vec<String> = (0..schedule.jobs.len())
+        .map(|job_index| match labels.get(job_index).copied().flatten() {
+            Some(label) => label.to_string(),
+            None => format!("J{}", job_index),
+        })
+        .collect();
+    let label_width = row_labels.iter().map(String::len).max().unwrap_or(0);
+
+    let mut chart = String::new();
+    for (job_index, label) in row_labels.iter().enumerate() {
+        let mut row = vec!['.'; columns];
+        for (start, duration) in schedule.segments_for_job(job_index) {
+            if duration > 0 {
+                let last = start + duration - 1;
+                for cell in &mut row[(start / scale) as usize..=(last / scale) as usize] {
+                    *cell = '#';
+                }
+            }
+        }
+        if config.show_job_labels {
+            chart.push_str(&format!("{:<width$} ", label, width = label_width));
+        }
+        chart.push('|');
+        chart.extend(row);
+        chart.push_str("|\n");
+    }
+    chart
+}
+
+/// Number of colors [`GanttBar::color_hint`] cycles through.
+pub const GANTT_COLORS: u32 = 8;
+
+/// One uninterrupted run of a job, a bar of a graphical Gantt chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GanttBar {
+    /// Index of the job in `schedule.jobs`.
+    pub job_index: usize,
+    pub start: u32,
+    pub end: u32,
+    /// Color of the bar, the job index modulo [`GANTT_COLORS`], so every
+    /// segment of a job has the same color.
+    pub color_hint: u32,
+}
+
+/// Returns a bar for every run of a job in the timetable of a schedule,
+/// in timetable order. Runs of no time get no bar.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::display::gantt::{to_gantt_bars, GanttBar};
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::schrage_preemptive;
+/// let js = JobList::new(vec![Job::new(0, 4, 1), Job::new(2, 2, 9)]);
+/// let bars = to_gantt_bars(&schrage_preemptive(&js));
+/// assert_eq!(bars.len(), 3);
+/// assert_eq!(bars[1], GanttBar { job_index: 1, start: 2, end: 4, color_hint: 1 });
+/// ```
+pub fn to_gantt_bars(schedule: &JobSchedule) -> Vec<GanttBar> {
+    schedule
+        .timetable
+        .iter()
+        .filter(|&&(_, _, duration)| duration > 0)
+        .map(|&(start, job_index, duration)| GanttBar {
+            job_index,
+            start,
+            end: start + duration,
+            color_hint: (job_index % GANTT_COLORS as usize) as u32,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::schrage::jobs::{labels, Job, JobList, LabeledJob};
+    use crate::schrage::schrage_preemptive;
+
+    #[test]
+    fn test_render_gantt_preemptive() {
+        let js = JobList::new(vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ]);
+        let schedule = schrage_preemptive(&js);
+        let config = GanttConfig {
+            scale: 10,
+            ..GanttConfig::default()
+        };
+        let expected = concat!(
+            "J0 |###...................|\n",
+            "J1 |..............#.......|\n",
+            "J2 |..#####...............|\n",
+            "J3 |.............#########|\n",
+        );
+        assert_eq!(render_gantt_with_config(&schedule, &config), expected);
+    }
+
+    #[test]
+    fn test_render_gantt_fits_max_width() {
+        let js = JobList::new(vec![Job::new(0, 4, 1), Job::new(2, 2, 9)]);
+        let schedule = schrage_preemptive(&js);
+        let config = GanttConfig {
+            scale: 1,
+            max_width: 3,
+            show_job_labels: false,
+        };
+        // Raised to 2 time units per character
+        assert_eq!(
+            render_gantt_with_config(&schedule, &config),
+            "|#.#|\n|.#.|\n"
+        );
+        let long = JobList::new(vec![Job::new(0, 500, 0), Job::new(600, 10, 0)]);
+        for row in render_gantt(&schrage_preemptive(&long)).lines() {
+            assert!(row.len() <= "J1 |".len() + 80 + 1);
+        }
+    }
+
+    #[test]
+    fn test_render_labeled_gantt() {
+        let jobs = vec![
+            LabeledJob::new("Job A", 0, 4, 1),
+            LabeledJob::from(Job::new(2, 2, 9)),
+        ];
+        let schedule =
+            schrage_preemptive(&jobs.iter().cloned().map(LabeledJob::into_job).collect());
+        let config = GanttConfig::default();
+        assert_eq!(
+            render_labeled_gantt(&schedule, &labels(&jobs), &config),
+            "Job A |##..##|\nJ1    |..##..|\n"
+        );
+        assert_eq!(
+            render_labeled_gantt(&schedule, &[], &config),
+            render_gantt(&schedule)
+        );
+    }
+
+    #[test]
+    fn test_render_gantt_empty() {
+        let schedule = schrage_preemptive(&JobList::new(Vec::new()));
+        assert_eq!(render_gantt(&schedule), "");
+    }
+
+    #[test]
+    fn test_to_gantt_bars() {
+        let mut rng = Rng::new(9);
+        for _ in 0..50 {
+            let js: JobList = (0..rng.gen_range(1, 20))
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 100),
+                        rng.gen_range(1, 20),
+                        rng.gen_range(0, 100),
+                    )
+                })
+                .collect();
+            let schedule = schrage_preemptive(&js);
+            let bars = to_gantt_bars(&schedule);
+
+            // The bars cover the processing times, the c max adds the
+            // cooldown time of the last job done
+            let covered: u32 = bars.iter().map(|bar| bar.end - bar.start).sum();
+            let total_p: u32 = js.iter().map(|job| job.processing_time).sum();
+            assert_eq!(covered, total_p);
+            let c_max = bars
+                .iter()
+                .map(|bar| bar.end + js.jobs[bar.job_index].cooldown_time)
+                .max()
+                .unwrap_or(0);
+            assert_eq!(c_max, schedule.c_max());
+
+            for (a, b) in bars.iter().zip(bars.iter().skip(1)) {
+                assert!(a.end <= b.start);
+                assert!(a.job_index != b.job_index || a.end < b.start);
+            }
+            for bar in &bars {
+                assert!(bar.start < bar.end);
+                assert_eq!(bar.color_hint, bar.job_index as u32 % GANTT_COLORS);
+            }
+        }
+        assert!(to_gantt_bars(&schrage_preemptive(&JobList::new(Vec::new()))).is_empty());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::{schrage_order, schrage_preemptive};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Returns the c max of the jobs run in the order given by their indices.
+fn c_max_of(jobs: &[Job], order: &[usize]) -> u32 {
+    JobList::new(order.iter().map(|&i| jobs[i]).collect()).c_max()
+}
+
+/// Lower bound `min(r) + sum(p) + min(q)` of a set of jobs.
+fn block_bound(jobs: &[Job], block: &[usize]) -> u32 {
+    let r = block.iter().map(|&i| jobs[i].delivery_time).min().unwrap();
+    let p: u32 = block.iter().map(|&i| jobs[i].processing_time).sum();
+    let q = block.iter().map(|&i| jobs[i].cooldown_time).min().unwrap();
+    r + p + q
+}
+
+/// Best solution found so far, in terms of the original jobs.
+struct Incumbent<'a> {
+    original: &'a [Job],
+    order: Vec<usize>,
+    c_max: u32,
+}
+
+/// Positions `(a, b, c)` in a sequence of jobs of the first and the last job
+/// of its critical path and of the job interfering with it, if any.
+///
+/// `b` is the last job that reaches the c max, `a` the first one of the
+/// block run without idle time up to `b`, and `c` the last job of the path
+/// with a shorter cooldown time than `b`.
+fn critical_path(sequence: &[Job]) -> (usize, usize, Option<usize>) {
+    let mut starts = vec![0; sequence.len()];
+    let mut ends = vec![0; sequence.len()];
+    let mut t = 0;
+    for (k, job) in sequence.iter().enumerate() {
+        starts[k] = cmp::max(t, job.delivery_time);
+        t = starts[k] + job.processing_time;
+        ends[k] = t;
+    }
+    let c_max = (0..sequence.len())
+        .map(|k| ends[k] + sequence[k].cooldown_time)
+        .max()
+        .unwrap();
+    let b = (0..sequence.len())
+        .rev()
+        .find(|&k| ends[k] + sequence[k].cooldown_time == c_max)
+        .unwrap();
+    let mut a = b;
+    while a > 0 && starts[a] == ends[a - 1] {
+        a -= 1;
+    }
+    let q_b = sequence[b].cooldown_time;
+    let c = (a..b).rev().find(|&k| sequence[k].cooldown_time < q_b);
+    (a, b, c)
+}
+
+/// Returns the position of the interference job of a schedule in Carlier's
+/// algorithm, the job Carlier's algorithm branches on.
+///
+/// That is the last job of the critical path (the jobs run without idle
+/// time up to the last job reaching the c max) with a shorter cooldown time
+/// than the last job of the path. Returns `None` if there is no such job,
+/// then no schedule is shorter if the schedule comes from Schrage, or if
+/// the schedule is empty.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::exact::carlier::carlier_identify_bottleneck;
+/// // The Schrage schedule, the job delivered at 10 holds back the critical path
+/// let schedule = JobList::new(vec![
+///     Job::new(0, 6, 17),
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(30, 2, 0),
+/// ]);
+/// assert_eq!(carlier_identify_bottleneck(&schedule), Some(1));
+/// ```
+pub fn carlier_identify_bottleneck(schedule: &JobList) -> Option<usize> {
+    if schedule.jobs.is_empty() {
+        return None;
+    }
+    critical_path(&schedule.jobs).2
+}
+
+/// One node of Carlier's algorithm on the (modified) jobs, `lower_bound` is
+/// the lower bound the node was entered with.
+fn branch(jobs: &mut [Job], lower_bound: u32, incumbent: &mut Incumbent) {
+    let pi = schrage_order(&JobList::new(jobs.to_vec()));
+    let c_max = c_max_of(incumbent.original, &pi);
+    if c_max < incumbent.c_max {
+        incumbent.c_max = c_max;
+        incumbent.order = pi.clone();
+    }
+
+    let sequence: Vec<Job> = pi.iter().map(|&i| jobs[i]).collect();
+    // The Schrage schedule reaches the lower bound, nothing to gain by branching
+    if JobList::new(sequence.clone()).c_max() <= lower_bound {
+        return;
+    }
+    // If the critical path has no interference job c
+    // the Schrage schedule of this node is optimal
+    let (_, b, c) = critical_path(&sequence);
+    let c = match c {
+        Some(c) => c,
+        None => return,
+    };
+
+    // K: the jobs after c on the critical path
+    let block: Vec<usize> = pi[c + 1..=b].to_vec();
+    let r_block = block.iter().map(|&i| jobs[i].delivery_time).min().unwrap();
+    let p_block: u32 = block.iter().map(|&i| jobs[i].processing_time).sum();
+    let q_block = block.iter().map(|&i| jobs[i].cooldown_time).min().unwrap();
+    let job_c = pi[c];
+    let mut block_with_c = block.clone();
+    block_with_c.push(job_c);
+
+    // Branch 1: c is run after all of K
+    let original_delivery_time = jobs[job_c].delivery_time;
+    jobs[job_c].delivery_time = cmp::max(original_delivery_time, r_block + p_block);
+    let bound = node_lower_bound(jobs, &block, &block_with_c);
+    if bound < incumbent.c_max {
+        branch(jobs, bound, incumbent);
+    }
+    jobs[job_c].delivery_time = original_delivery_time;
+
+    // Branch 2: c is run before all of K
+    let original_cooldown_time = jobs[job_c].cooldown_time;
+    jobs[job_c].cooldown_time = cmp::max(original_cooldown_time, q_block + p_block);
+    let bound = node_lower_bound(jobs, &block, &block_with_c);
+    if bound < incumbent.c_max {
+        branch(jobs, bound, incumbent);
+    }
+    jobs[job_c].cooldown_time = original_cooldown_time;
+}
+
+/// Lower bound of a node: the preemptive Schrage c max (Jackson's
+/// preemptive schedule) and the bounds of the critical block with and without c.
+fn node_lower_bound(jobs: &[Job], block: &[usize], block_with_c: &[usize]) -> u32 {
+    let preemptive = schrage_preemptive(&JobList::new(jobs.to_vec())).c_max();
+    cmp::max(
+        preemptive,
+        cmp::max(block_bound(jobs, block), block_bound(jobs, block_with_c)),
+    )
+}
+
+/// Carlier's algorithm, an exact method for the single machine problem with
+/// delivery and cooldown times (1|r_j,q_j|Cmax).
+///
+/// Every node runs the Schrage algorithm and finds the critical path of its
+/// schedule. If the path holds a job `c` with a shorter cooldown time than
+/// its last job, the node branches on running `c` either after or before the
+/// jobs that follow it on the path, by raising its delivery or cooldown time.
+/// Nodes whose lower bound (the preemptive Schrage c max) reaches the best
+/// c max found so far are pruned, and a node whose Schrage schedule already
+/// reaches its lower bound is not branched further.
+///
+/// Returns the optimal sequence and its c max, `(JobList::new(vec![]), 0)`
+/// for no jobs.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::exact::carlier::carlier;
+/// let jobs = vec![
+///     Job::new(10, 5, 7),  // 1
+///     Job::new(13, 6, 26), // 2
+///     Job::new(11, 7, 24), // 3
+///     Job::new(20, 4, 21), // 4
+///     Job::new(30, 3, 8),  // 5
+///     Job::new(0, 6, 17),  // 6
+///     Job::new(30, 2, 0),  // 7
+/// ];
+/// let (result, c_max) = carlier(jobs);
+/// assert_eq!(c_max, 50);
+/// assert_eq!(result.c_max(), 50);
+/// ```
+pub fn carlier(jobs: Vec<Job>) -> (JobList, u32) {
+    if jobs.is_empty() {
+        return (JobList::new(Vec::new()), 0);
+    }
+    let mut incumbent = Incumbent {
+        original: &jobs,
+        order: Vec::new(),
+        c_max: u32::MAX,
+    };
+    let mut working = jobs.clone();
+    let bound = schrage_preemptive(&JobList::new(jobs.clone())).c_max();
+    branch(&mut working, bound, &mut incumbent);
+    let result = JobList::new(incumbent.order.iter().map(|&i| jobs[i]).collect());
+    (result, incumbent.c_max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::branch_and_bound;
+    use crate::rng::Rng;
+    use crate::schrage::schrage;
+
+    #[test]
+    fn test_carlier_matches_branch_and_bound() {
+        let mut rng = Rng::new(5);
+        for n in 1..12 {
+            for _ in 0..5 {
+                let jobs: Vec<Job> = (0..n)
+                    .map(|_| {
+                        Job::new(
+                            rng.gen_range(0, 50),
+                            rng.gen_range(1, 20),
+                            rng.gen_range(0, 50),
+                        )
+                    })
+                    .collect();
+                let (result, c_max) = carlier(jobs.clone());
+                assert_eq!(result.jobs.len(), n);
+                assert_eq!(result.c_max(), c_max);
+                assert_eq!(c_max, branch_and_bound(jobs).c_max());
+            }
+        }
+    }
+
+    #[test]
+    fn test_carlier_50_jobs() {
+        let mut rng = Rng::new(15);
+        for _ in 0..10 {
+            let jobs: Vec<Job> = (0..50)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 2000),
+                        rng.gen_range(1, 100),
+                        rng.gen_range(0, 2000),
+                    )
+                })
+                .collect();
+            let lower_bound = schrage_preemptive(&JobList::new(jobs.clone())).c_max();
+            let (result, c_max) = carlier(jobs);
+            assert_eq!(result.jobs.len(), 50);
+            assert_eq!(result.c_max(), c_max);
+            assert!(c_max >= lower_bound);
+        }
+    }
+
+    #[test]
+    fn test_carlier_ex3() {
+        let jobs = vec![
+            Job::new(162, 52, 241), // 1
+            Job::new(103, 68, 470), // 2
+            Job::new(39, 38, 340),  // 3
+            Job::new(394, 34, 400), // 4
+            Job::new(15, 86, 700),  // 5
+            Job::new(144, 73, 536), // 6
+            Job::new(51, 52, 403),  // 7
+            Job::new(233, 68, 23),  // 8
+            Job::new(183, 17, 641), // 9
+            Job::new(728, 18, 640), // 10
+            Job::new(667, 80, 92),  // 11
+            Job::new(57, 21, 76),   // 12
+            Job::new(35, 37, 386),  // 13
+            Job::new(567, 71, 618), // 14
+            Job::new(226, 5, 629),  // 15
+            Job::new(162, 80, 575), // 16
+            Job::new(588, 45, 632), // 17
+            Job::new(556, 23, 79),  // 18
+            Job::new(715, 8, 93),   // 19
+            Job::new(598, 45, 200), // 20
+        ];
+        let (result, c_max) = carlier(jobs);
+        // Schrage gives 1399, the optimum reaches the preemptive lower bound
+        assert_eq!(c_max, 1386);
+        assert_eq!(result.c_max(), 1386);
+    }
+
+    #[test]
+    fn test_carlier_identify_bottleneck() {
+        let schedule = JobList::new(vec![
+            Job::new(0, 6, 17),  // 6
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(30, 2, 0),  // 7
+        ]);
+        // The critical path runs from job 1 to job 4, job 1 has
+        // the only cooldown time shorter than the one of job 4
+        assert_eq!(critical_path(&schedule.jobs), (1, 4, Some(1)));
+        assert_eq!(carlier_identify_bottleneck(&schedule), Some(1));
+
+        // Jobs delivered together are run by descending cooldown time,
+        // there is no interference job and Schrage is optimal
+        let simultaneous = schrage(&JobList::new(vec![
+            Job::new(0, 5, 1),
+            Job::new(0, 2, 10),
+            Job::new(0, 3, 4),
+        ]))
+        .job_list;
+        assert_eq!(carlier_identify_bottleneck(&simultaneous), None);
+        assert_eq!(carlier_identify_bottleneck(&JobList::new(Vec::new())), None);
+    }
+
+    #[test]
+    fn test_carlier_empty() {
+        let (result, c_max) = carlier(Vec::new());
+        assert!(result.jobs.is_empty());
+        assert_eq!(c_max, 0);
+    }
+}
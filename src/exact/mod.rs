@@ -0,0 +1,214 @@
+pub mod carlier;
+
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::{schrage, schrage_preemptive};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Node of the branch and bound search tree, a partial sequence of jobs.
+struct Node {
+    /// Indices of the jobs scheduled so far, in order
+    sequence: Vec<usize>,
+    /// Whether a job is already in `sequence`
+    scheduled: Vec<bool>,
+    /// Time at which the machine finishes the partial sequence
+    time: u32,
+    /// C max of the partial sequence
+    c_max: u32,
+    /// Lower bound on the c max of every completion of the partial sequence
+    lower_bound: u32,
+}
+
+impl Node {
+    fn root(n: usize) -> Node {
+        Node {
+            sequence: Vec::new(),
+            scheduled: vec![false; n],
+            time: 0,
+            c_max: 0,
+            lower_bound: 0,
+        }
+    }
+
+    /// Appends a job to the partial sequence and computes the new lower bound.
+    fn child(&self, jobs: &[Job], next: usize) -> Node {
+        let job = &jobs[next];
+        let time = cmp::max(self.time, job.delivery_time) + job.processing_time;
+        let mut sequence = self.sequence.clone();
+        sequence.push(next);
+        let mut scheduled = self.scheduled.clone();
+        scheduled[next] = true;
+        let c_max = cmp::max(self.c_max, time + job.cooldown_time);
+
+        // The remaining jobs can not be delivered before the machine is free,
+        // the preemptive schedule of them is a lower bound on any sequence.
+        let remaining: Vec<Job> = (0..jobs.len())
+            .filter(|&i| !scheduled[i])
+            .map(|i| {
+                let mut j = jobs[i];
+                j.delivery_time = cmp::max(j.delivery_time, time);
+                j
+            })
+            .collect();
+        let remaining_bound = schrage_preemptive(&JobList::new(remaining)).c_max();
+
+        Node {
+            sequence,
+            scheduled,
+            time,
+            c_max,
+            lower_bound: cmp::max(c_max, remaining_bound),
+        }
+    }
+}
+
+/// Branch and bound exact solver for the single machine problem with
+/// delivery and cooldown times (1|r_j,q_j|Cmax).
+///
+/// Jobs are appended one at a time to a partial sequence. The Schrage
+/// schedule is the initial upper bound and a node is pruned as soon as its
+/// lower bound reaches the best c max found so far. The lower bound of a node
+/// is the preemptive Schrage c max of the remaining jobs, which is never
+/// weaker than `max(r_j + p_j + q_j)` over them. Only jobs that could start
+/// before any other remaining job finishes are branched on, which keeps all
+/// optimal (active) schedules reachable.
+///
+/// Meant for small instances (n up to about 20), the running time
+/// is exponential in the worst case.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::exact::branch_and_bound;
+/// let jobs = vec![
+///     Job::new(10, 5, 7),  // 1
+///     Job::new(13, 6, 26), // 2
+///     Job::new(11, 7, 24), // 3
+///     Job::new(20, 4, 21), // 4
+///     Job::new(30, 3, 8),  // 5
+///     Job::new(0, 6, 17),  // 6
+///     Job::new(30, 2, 0),  // 7
+/// ];
+/// let result = branch_and_bound(jobs);
+/// assert_eq!(result.c_max(), 50);
+/// ```
+pub fn branch_and_bound(jobs: Vec<Job>) -> JobList {
+    if jobs.is_empty() {
+        return JobList::new(Vec::new());
+    }
+    let mut best = schrage(&JobList::new(jobs.clone())).job_list;
+    let mut best_c_max = best.c_max();
+
+    let mut stack = vec![Node::root(jobs.len())];
+    while let Some(node) = stack.pop() {
+        if node.lower_bound >= best_c_max {
+            continue;
+        }
+        if node.sequence.len() == jobs.len() {
+            best = JobList::new(node.sequence.iter().map(|&i| jobs[i]).collect());
+            best_c_max = node.c_max;
+            continue;
+        }
+
+        // No job may start after another remaining job could already be done
+        let earliest_end = (0..jobs.len())
+            .filter(|&i| !node.scheduled[i])
+            .map(|i| cmp::max(node.time, jobs[i].delivery_time) + jobs[i].processing_time)
+            .min()
+            .unwrap();
+        let mut children: Vec<Node> = (0..jobs.len())
+            .filter(|&i| !node.scheduled[i])
+            .filter(|&i| cmp::max(node.time, jobs[i].delivery_time) < earliest_end)
+            .map(|i| node.child(&jobs, i))
+            .filter(|child| child.lower_bound < best_c_max)
+            .collect();
+        // Explore the most promising child first
+        children.sort_by_key(|child| cmp::Reverse(child.lower_bound));
+        stack.extend(children);
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::test_util::min_over_permutations;
+
+    fn brute_force(jobs: &[Job]) -> u32 {
+        min_over_permutations(jobs, |jobs: &[Job]| JobList::new(jobs.to_vec()).c_max())
+    }
+
+    #[test]
+    fn test_branch_and_bound_matches_brute_force() {
+        let mut rng = Rng::new(0);
+        for n in 1..8 {
+            for _ in 0..5 {
+                let jobs: Vec<Job> = (0..n)
+                    .map(|_| {
+                        Job::new(
+                            rng.gen_range(0, 30),
+                            rng.gen_range(1, 10),
+                            rng.gen_range(0, 30),
+                        )
+                    })
+                    .collect();
+                let result = branch_and_bound(jobs.clone());
+                assert_eq!(result.jobs.len(), n);
+                assert_eq!(result.c_max(), brute_force(&jobs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_branch_and_bound_ex3() {
+        let jobs = vec![
+            Job::new(162, 52, 241), // 1
+            Job::new(103, 68, 470), // 2
+            Job::new(39, 38, 340),  // 3
+            Job::new(394, 34, 400), // 4
+            Job::new(15, 86, 700),  // 5
+            Job::new(144, 73, 536), // 6
+            Job::new(51, 52, 403),  // 7
+            Job::new(233, 68, 23),  // 8
+            Job::new(183, 17, 641), // 9
+            Job::new(728, 18, 640), // 10
+            Job::new(667, 80, 92),  // 11
+            Job::new(57, 21, 76),   // 12
+            Job::new(35, 37, 386),  // 13
+            Job::new(567, 71, 618), // 14
+            Job::new(226, 5, 629),  // 15
+            Job::new(162, 80, 575), // 16
+            Job::new(588, 45, 632), // 17
+            Job::new(556, 23, 79),  // 18
+            Job::new(715, 8, 93),   // 19
+            Job::new(598, 45, 200), // 20
+        ];
+        let lower_bound = schrage_preemptive(&JobList::new(jobs.clone())).c_max();
+        let result = branch_and_bound(jobs);
+        assert_eq!(result.jobs.len(), 20);
+        // Schrage gives 1399, the optimum reaches the preemptive lower bound
+        assert_eq!(lower_bound, 1386);
+        assert_eq!(result.c_max(), lower_bound);
+    }
+
+    #[test]
+    fn test_branch_and_bound_edge_cases() {
+        assert!(branch_and_bound(Vec::new()).jobs.is_empty());
+
+        let single = branch_and_bound(vec![Job::new(3, 4, 5)]);
+        assert_eq!(single.jobs, vec![Job::new(3, 4, 5)]);
+        assert_eq!(single.c_max(), 12);
+
+        let same_delivery = vec![
+            Job::new(5, 3, 1),
+            Job::new(5, 2, 9),
+            Job::new(5, 4, 4),
+            Job::new(5, 1, 7),
+        ];
+        let result = branch_and_bound(same_delivery.clone());
+        assert_eq!(result.c_max(), brute_force(&same_delivery));
+    }
+}
@@ -0,0 +1,85 @@
+//! C interface of the single machine algorithms, enabled with the `ffi`
+//! feature. The header `proc_opt.h` can be generated with `cbindgen`, see
+//! the README.
+
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::schrage;
+use std::panic;
+use std::slice;
+
+/// Returned on success.
+pub const FFI_OK: i32 = 0;
+/// Returned if a pointer argument is null.
+pub const FFI_NULL_POINTER: i32 = -1;
+/// Returned if the algorithm panicked, nothing is written to the output.
+pub const FFI_PANIC: i32 = -2;
+
+/// A [`Job`] with the layout of the C struct
+/// `{ uint32_t delivery_time; uint32_t processing_time; uint32_t cooldown_time; }`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CJob {
+    pub delivery_time: u32,
+    pub processing_time: u32,
+    pub cooldown_time: u32,
+}
+
+impl From<CJob> for Job {
+    fn from(job: CJob) -> Job {
+        Job::new(job.delivery_time, job.processing_time, job.cooldown_time)
+    }
+}
+
+impl From<Job> for CJob {
+    fn from(job: Job) -> CJob {
+        CJob {
+            delivery_time: job.delivery_time,
+            processing_time: job.processing_time,
+            cooldown_time: job.cooldown_time,
+        }
+    }
+}
+
+/// Runs [`schrage`] on the `n` jobs at `jobs` and writes the jobs in the
+/// order they are run to `out`, and their number to `out_len`.
+///
+/// Returns [`FFI_OK`], [`FFI_NULL_POINTER`] if any of the pointers is null
+/// (`jobs` may be null if `n` is 0) or [`FFI_PANIC`]. It never unwinds into
+/// the caller.
+///
+/// # Safety
+///
+/// `jobs` must point to `n` valid jobs and `out` to space for `n` jobs that
+/// does not overlap them, `out_len` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn schrage_ffi(
+    jobs: *const CJob,
+    n: usize,
+    out: *mut CJob,
+    out_len: *mut usize,
+) -> i32 {
+    if (jobs.is_null() && n > 0) || out.is_null() || out_len.is_null() {
+        return FFI_NULL_POINTER;
+    }
+    let input = if n == 0 {
+        &[]
+    } else {
+        // SAFETY: non null and valid for n jobs by the contract of the function
+        unsafe { slice::from_raw_parts(jobs, n) }
+    };
+    let result = panic::catch_unwind(|| {
+        let js = JobList::new(input.iter().map(|&job| Job::from(job)).collect());
+        schrage(&js).job_list.jobs
+    });
+    let order = match result {
+        Ok(order) => order,
+        Err(_) => return FFI_PANIC,
+    };
+    for (i, job) in order.iter().enumerate() {
+        // SAFETY: out has space for n jobs and schrage returns n jobs
+        unsafe { out.add(i).write(CJob::from(*job)) };
+    }
+    // SAFETY: non null and valid for a write by the contract of the function
+    unsafe { out_len.write(order.len()) };
+    FFI_OK
+}
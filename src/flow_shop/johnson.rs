@@ -0,0 +1,212 @@
+use crate::flow_shop::{flow_shop_c_max, FlowShopJob};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Job of a two machine flow shop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwoMachineJob {
+    /// Identifier of the job, not used by the algorithms.
+    pub id: usize,
+    /// Processing time on the first machine.
+    pub p1: u32,
+    /// Processing time on the second machine.
+    pub p2: u32,
+}
+
+impl TwoMachineJob {
+    /// Creates a new [`TwoMachineJob`].
+    pub fn new(id: usize, p1: u32, p2: u32) -> TwoMachineJob {
+        TwoMachineJob { id, p1, p2 }
+    }
+}
+
+/// Johnson's algorithm, optimal for the two machine flow shop (F2||Cmax).
+///
+/// The jobs shorter on the first machine (`p1 <= p2`) go first, by ascending
+/// `p1`, then the other ones by descending `p2`. Runs in O(n log n).
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::johnson::{flow_shop_cmax, johnson, TwoMachineJob};
+/// let jobs = vec![
+///     TwoMachineJob::new(1, 3, 2),
+///     TwoMachineJob::new(2, 1, 4),
+///     TwoMachineJob::new(3, 2, 2),
+/// ];
+/// let result = johnson(jobs);
+/// let ids: Vec<usize> = result.iter().map(|j| j.id).collect();
+/// assert_eq!(ids, vec![2, 3, 1]);
+/// assert_eq!(flow_shop_cmax(&result), 9);
+/// ```
+pub fn johnson(jobs: Vec<TwoMachineJob>) -> Vec<TwoMachineJob> {
+    let (mut first, mut second): (Vec<TwoMachineJob>, Vec<TwoMachineJob>) =
+        jobs.into_iter().partition(|job| job.p1 <= job.p2);
+    first.sort_by_key(|job| job.p1);
+    second.sort_by_key(|job| cmp::Reverse(job.p2));
+    first.append(&mut second);
+    first
+}
+
+/// Returns the c max of a two machine flow shop schedule, the
+/// [`flow_shop_c_max`] of the same jobs with two machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::johnson::{flow_shop_cmax, TwoMachineJob};
+/// let schedule = vec![TwoMachineJob::new(1, 2, 5), TwoMachineJob::new(2, 4, 1)];
+/// assert_eq!(flow_shop_cmax(&schedule), 8);
+/// ```
+pub fn flow_shop_cmax(schedule: &[TwoMachineJob]) -> u32 {
+    let schedule: Vec<FlowShopJob> = schedule
+        .iter()
+        .map(|job| FlowShopJob::new(vec![job.p1, job.p2]))
+        .collect();
+    flow_shop_c_max(&schedule)
+}
+
+/// Extension of Johnson's algorithm to permutation flow shops with any
+/// number of machines (the CDS heuristic).
+///
+/// For every split `k` of the `m` machines a synthetic two machine job is made
+/// of every job, with the sum of its processing times on the first `k`
+/// machines and on the remaining ones. Johnson's algorithm orders the
+/// synthetic jobs and the best of the `m - 1` orders is returned.
+///
+/// Not optimal for more than two machines.
+///
+/// # Panics
+///
+/// Panics if the jobs do not all have the same number of machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::johnson::johnson_extended;
+/// use proc_opt::flow_shop::{flow_shop_c_max, FlowShopJob};
+/// let jobs = vec![
+///     FlowShopJob::new(vec![5, 4, 4]),
+///     FlowShopJob::new(vec![2, 3, 5]),
+///     FlowShopJob::new(vec![4, 1, 3]),
+/// ];
+/// let result = johnson_extended(jobs.clone());
+/// assert!(flow_shop_c_max(&result) <= flow_shop_c_max(&jobs));
+/// ```
+pub fn johnson_extended(jobs: Vec<FlowShopJob>) -> Vec<FlowShopJob> {
+    let machines = match jobs.first() {
+        Some(job) => job.processing_times.len(),
+        None => return jobs,
+    };
+    let mut best = jobs.clone();
+    let mut best_c_max = flow_shop_c_max(&best);
+    for k in 1..machines {
+        let synthetic: Vec<TwoMachineJob> = jobs
+            .iter()
+            .enumerate()
+            .map(|(id, job)| {
+                let times = &job.processing_times;
+                TwoMachineJob::new(id, times[..k].iter().sum(), times[k..].iter().sum())
+            })
+            .collect();
+        let candidate: Vec<FlowShopJob> = johnson(synthetic)
+            .iter()
+            .map(|job| jobs[job.id].clone())
+            .collect();
+        let c_max = flow_shop_c_max(&candidate);
+        if c_max < best_c_max {
+            best = candidate;
+            best_c_max = c_max;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::min_over_permutations;
+
+    fn brute_force(jobs: &[TwoMachineJob]) -> u32 {
+        min_over_permutations(jobs, flow_shop_cmax)
+    }
+
+    #[test]
+    fn test_johnson_six_jobs() {
+        let jobs = vec![
+            TwoMachineJob::new(1, 4, 6),
+            TwoMachineJob::new(2, 8, 3),
+            TwoMachineJob::new(3, 3, 7),
+            TwoMachineJob::new(4, 6, 2),
+            TwoMachineJob::new(5, 7, 8),
+            TwoMachineJob::new(6, 5, 4),
+        ];
+        let result = johnson(jobs.clone());
+        let ids: Vec<usize> = result.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![3, 1, 5, 6, 2, 4]);
+        assert_eq!(flow_shop_cmax(&result), 35);
+        assert_eq!(brute_force(&jobs), 35);
+    }
+
+    #[test]
+    fn test_johnson_is_optimal() {
+        let mut rng = crate::rng::Rng::new(8);
+        for n in 1..7 {
+            let jobs: Vec<TwoMachineJob> = (0..n)
+                .map(|id| TwoMachineJob::new(id, rng.gen_range(1, 20), rng.gen_range(1, 20)))
+                .collect();
+            assert_eq!(flow_shop_cmax(&johnson(jobs.clone())), brute_force(&jobs));
+        }
+    }
+
+    #[test]
+    fn test_johnson_extended_is_permutation() {
+        let jobs = vec![
+            FlowShopJob::new(vec![5, 4, 4, 2]),
+            FlowShopJob::new(vec![2, 3, 5, 6]),
+            FlowShopJob::new(vec![4, 1, 3, 7]),
+            FlowShopJob::new(vec![6, 6, 2, 1]),
+            FlowShopJob::new(vec![1, 7, 3, 3]),
+        ];
+        let result = johnson_extended(jobs.clone());
+        assert_eq!(result.len(), jobs.len());
+        for job in &jobs {
+            assert!(result.contains(job));
+        }
+        assert!(flow_shop_c_max(&result) <= flow_shop_c_max(&jobs));
+    }
+
+    #[test]
+    fn test_johnson_extended_two_machines() {
+        // With two machines the only split is Johnson's algorithm itself
+        let jobs = vec![
+            FlowShopJob::new(vec![4, 6]),
+            FlowShopJob::new(vec![8, 3]),
+            FlowShopJob::new(vec![3, 7]),
+            FlowShopJob::new(vec![6, 2]),
+            FlowShopJob::new(vec![7, 8]),
+            FlowShopJob::new(vec![5, 4]),
+        ];
+        assert_eq!(flow_shop_c_max(&johnson_extended(jobs)), 35);
+        assert!(johnson_extended(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_flow_shop_cmax_matches_general_flow_shop() {
+        let schedule = vec![
+            TwoMachineJob::new(1, 1, 2),
+            TwoMachineJob::new(2, 3, 6),
+            TwoMachineJob::new(3, 6, 6),
+            TwoMachineJob::new(4, 7, 5),
+            TwoMachineJob::new(5, 5, 2),
+        ];
+        let general: Vec<FlowShopJob> = schedule
+            .iter()
+            .map(|j| FlowShopJob::new(vec![j.p1, j.p2]))
+            .collect();
+        assert_eq!(flow_shop_cmax(&schedule), flow_shop_c_max(&general));
+        assert_eq!(flow_shop_cmax(&[]), 0);
+        assert!(johnson(Vec::new()).is_empty());
+    }
+}
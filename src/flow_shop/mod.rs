@@ -0,0 +1,405 @@
+pub mod johnson;
+pub mod neh;
+
+use crate::schrage::jobs::Job;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{cmp, fmt};
+
+/// Job of a permutation flow shop.
+///
+/// `processing_times[k]` is the processing time of the job on machine `k`,
+/// every job visits the machines in the same order `0, 1, ..., m - 1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowShopJob {
+    pub processing_times: Vec<u32>,
+}
+
+impl FlowShopJob {
+    /// Creates a new [`FlowShopJob`].
+    pub fn new(processing_times: Vec<u32>) -> FlowShopJob {
+        FlowShopJob { processing_times }
+    }
+
+    /// Returns the sum of the processing times on all machines of this [`FlowShopJob`].
+    pub fn total_processing_time(&self) -> u32 {
+        self.processing_times.iter().sum()
+    }
+}
+
+impl fmt::Display for FlowShopJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.processing_times)
+    }
+}
+
+/// Returns the c max of a permutation flow shop schedule.
+///
+/// Returns 0 if the schedule is empty.
+///
+/// # Panics
+///
+/// Panics if the jobs do not all have the same number of machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::{flow_shop_c_max, FlowShopJob};
+/// let schedule = vec![
+///     FlowShopJob::new(vec![1, 2]),
+///     FlowShopJob::new(vec![3, 6]),
+///     FlowShopJob::new(vec![6, 6]),
+///     FlowShopJob::new(vec![7, 5]),
+///     FlowShopJob::new(vec![5, 2]),
+/// ];
+/// assert_eq!(flow_shop_c_max(&schedule), 24);
+/// ```
+pub fn flow_shop_c_max(schedule: &[FlowShopJob]) -> u32 {
+    flow_shop_job_completion_times(schedule)
+        .last()
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Returns the completion time of every job of a permutation flow shop
+/// schedule on the last machine, in schedule order.
+///
+/// # Panics
+///
+/// Panics if the jobs do not all have the same number of machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::{flow_shop_job_completion_times, FlowShopJob};
+/// let schedule = vec![FlowShopJob::new(vec![1, 2]), FlowShopJob::new(vec![3, 6])];
+/// assert_eq!(flow_shop_job_completion_times(&schedule), vec![3, 10]);
+/// ```
+pub fn flow_shop_job_completion_times(schedule: &[FlowShopJob]) -> Vec<u32> {
+    let machines = match schedule.first() {
+        Some(job) => job.processing_times.len(),
+        None => return Vec::new(),
+    };
+    // completion[k] is the completion time of the last scheduled job
+    // on machine k: C(i, k) = max(C(i - 1, k), C(i, k - 1)) + p(i, k),
+    // where C(i - 1, k) is the value still stored in completion[k]
+    // and C(i, k - 1) the one just computed for the previous machine.
+    let mut completion = vec![0; machines];
+    schedule
+        .iter()
+        .map(|job| {
+            assert_eq!(
+                job.processing_times.len(),
+                machines,
+                "all jobs must have the same number of machines"
+            );
+            let mut previous_machine = 0;
+            for (k, &p) in job.processing_times.iter().enumerate() {
+                completion[k] = cmp::max(completion[k], previous_machine) + p;
+                previous_machine = completion[k];
+            }
+            previous_machine
+        })
+        .collect()
+}
+
+/// Returns the tardiness `max(0, C_j - d_j)` of every job of a permutation
+/// flow shop schedule, where `C_j` is its completion time on the last machine
+/// and `d_j` its due date, `due_dates[j]` for the job at position `j`.
+///
+/// # Panics
+///
+/// Panics if there is not one due date per job or the jobs do not all
+/// have the same number of machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::{flow_shop_tardiness, FlowShopJob};
+/// let schedule = vec![FlowShopJob::new(vec![1, 2]), FlowShopJob::new(vec![3, 6])];
+/// assert_eq!(flow_shop_tardiness(&schedule, &[5, 7]), vec![0, 3]);
+/// ```
+pub fn flow_shop_tardiness(schedule: &[FlowShopJob], due_dates: &[u32]) -> Vec<u32> {
+    assert_eq!(
+        schedule.len(),
+        due_dates.len(),
+        "every job needs a due date"
+    );
+    flow_shop_job_completion_times(schedule)
+        .into_iter()
+        .zip(due_dates)
+        .map(|(completion, &due_date)| completion.saturating_sub(due_date))
+        .collect()
+}
+
+/// Relaxes a permutation flow shop to the single machine problem
+/// of machine `machine_idx`.
+///
+/// Every job keeps its processing time on that machine, its delivery time is
+/// its completion time on the earlier machines if it is never delayed
+/// (the sum of its processing times on them) and its cooldown time is the sum
+/// of its processing times on the later machines. The optimal c max of the
+/// result is a lower bound on the c max of any flow shop schedule.
+///
+/// # Panics
+///
+/// Panics if `machine_idx` is out of bounds for any job.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::{extract_machine_sub_problem, FlowShopJob};
+/// use proc_opt::schrage::jobs::Job;
+/// let jobs = vec![FlowShopJob::new(vec![5, 4, 4]), FlowShopJob::new(vec![2, 3, 5])];
+/// assert_eq!(
+///     extract_machine_sub_problem(&jobs, 1),
+///     vec![Job::new(5, 4, 4), Job::new(2, 3, 5)]
+/// );
+/// ```
+pub fn extract_machine_sub_problem(jobs: &[FlowShopJob], machine_idx: usize) -> Vec<Job> {
+    jobs.iter()
+        .map(|job| {
+            let times = &job.processing_times;
+            Job::new(
+                times[..machine_idx].iter().sum(),
+                times[machine_idx],
+                times[machine_idx + 1..].iter().sum(),
+            )
+        })
+        .collect()
+}
+
+/// Decodes a random key encoding into a permutation flow shop schedule.
+///
+/// The jobs are run by ascending key, `keys[j]` being the key of `jobs[j]`.
+/// Jobs with equal keys keep their order in `jobs`.
+///
+/// # Panics
+///
+/// Panics if there is not one key per job.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::{random_key_decode, FlowShopJob};
+/// let jobs = vec![FlowShopJob::new(vec![1, 2]), FlowShopJob::new(vec![3, 6])];
+/// assert_eq!(random_key_decode(&[0.7, 0.2], &jobs), vec![jobs[1].clone(), jobs[0].clone()]);
+/// ```
+pub fn random_key_decode(keys: &[f64], jobs: &[FlowShopJob]) -> Vec<FlowShopJob> {
+    assert_eq!(keys.len(), jobs.len(), "every job needs a key");
+    let mut order: Vec<usize> = (0..jobs.len()).collect();
+    order.sort_by(|&a, &b| keys[a].total_cmp(&keys[b]));
+    order.into_iter().map(|j| jobs[j].clone()).collect()
+}
+
+/// Returns a random key encoding of a permutation flow shop schedule of
+/// the jobs `original`, the inverse of [`random_key_decode`].
+///
+/// The key of `original[j]` is `k / n`, where `k` is the position of the job
+/// in `schedule` and `n` the number of jobs, so every key is in `[0, 1)`.
+/// Equal jobs are matched in the order they appear in both.
+///
+/// # Panics
+///
+/// Panics if `schedule` is not a permutation of `original`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::{random_key_decode, random_key_encode, FlowShopJob};
+/// let jobs = vec![FlowShopJob::new(vec![1, 2]), FlowShopJob::new(vec![3, 6])];
+/// let schedule = vec![jobs[1].clone(), jobs[0].clone()];
+/// let keys = random_key_encode(&schedule, &jobs);
+/// assert_eq!(keys, vec![0.5, 0.0]);
+/// assert_eq!(random_key_decode(&keys, &jobs), schedule);
+/// ```
+pub fn random_key_encode(schedule: &[FlowShopJob], original: &[FlowShopJob]) -> Vec<f64> {
+    assert_eq!(
+        schedule.len(),
+        original.len(),
+        "the schedule must be a permutation of the jobs"
+    );
+    let n = original.len() as f64;
+    let mut used = vec![false; schedule.len()];
+    original
+        .iter()
+        .map(|job| {
+            let position = (0..schedule.len())
+                .find(|&k| !used[k] && schedule[k] == *job)
+                .expect("the schedule must be a permutation of the jobs");
+            used[position] = true;
+            position as f64 / n
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::schrage::jobs::JobList;
+    use crate::schrage::{schrage, schrage_preemptive};
+
+    #[test]
+    fn test_flow_shop_c_max_two_machines() {
+        // Johnson's order of the classic 5 job example
+        let schedule = vec![
+            FlowShopJob::new(vec![1, 2]),
+            FlowShopJob::new(vec![3, 6]),
+            FlowShopJob::new(vec![6, 6]),
+            FlowShopJob::new(vec![7, 5]),
+            FlowShopJob::new(vec![5, 2]),
+        ];
+        assert_eq!(flow_shop_c_max(&schedule), 24);
+    }
+
+    #[test]
+    fn test_flow_shop_c_max_three_machines() {
+        let schedule = vec![
+            FlowShopJob::new(vec![5, 4, 4]),
+            FlowShopJob::new(vec![2, 3, 5]),
+            FlowShopJob::new(vec![4, 1, 3]),
+        ];
+        // Machine 1: 5, 7, 11
+        // Machine 2: 9, 12, 13
+        // Machine 3: 13, 18, 21
+        assert_eq!(flow_shop_c_max(&schedule), 21);
+    }
+
+    #[test]
+    fn test_flow_shop_c_max_degenerate() {
+        assert_eq!(flow_shop_c_max(&[]), 0);
+        let single_machine = vec![FlowShopJob::new(vec![3]), FlowShopJob::new(vec![4])];
+        assert_eq!(flow_shop_c_max(&single_machine), 7);
+        let single_job = vec![FlowShopJob::new(vec![3, 4, 5])];
+        assert_eq!(flow_shop_c_max(&single_job), 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_flow_shop_c_max_ragged() {
+        flow_shop_c_max(&[FlowShopJob::new(vec![3, 4]), FlowShopJob::new(vec![4])]);
+    }
+
+    #[test]
+    fn test_flow_shop_job_completion_times() {
+        let schedule = vec![
+            FlowShopJob::new(vec![5, 4, 4]),
+            FlowShopJob::new(vec![2, 3, 5]),
+            FlowShopJob::new(vec![4, 1, 3]),
+        ];
+        assert_eq!(flow_shop_job_completion_times(&schedule), vec![13, 18, 21]);
+        assert!(flow_shop_job_completion_times(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_flow_shop_tardiness() {
+        let schedule = vec![
+            FlowShopJob::new(vec![5, 4, 4]),
+            FlowShopJob::new(vec![2, 3, 5]),
+            FlowShopJob::new(vec![4, 1, 3]),
+        ];
+        let due_dates = [15, 10, 21];
+        let completion_times = flow_shop_job_completion_times(&schedule);
+        let tardiness = flow_shop_tardiness(&schedule, &due_dates);
+        assert_eq!(tardiness, vec![0, 8, 0]);
+        for j in 0..schedule.len() {
+            let lateness = completion_times[j] as i64 - due_dates[j] as i64;
+            assert_eq!(tardiness[j] as i64, cmp::max(0, lateness));
+        }
+        assert_eq!(tardiness.iter().max(), Some(&8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_flow_shop_tardiness_missing_due_date() {
+        flow_shop_tardiness(&[FlowShopJob::new(vec![3, 4])], &[]);
+    }
+
+    #[test]
+    fn test_random_key_round_trip() {
+        let jobs = vec![
+            FlowShopJob::new(vec![5, 4, 4]),
+            FlowShopJob::new(vec![2, 3, 5]),
+            FlowShopJob::new(vec![4, 1, 3]),
+            FlowShopJob::new(vec![2, 3, 5]),
+        ];
+        let mut rng = Rng::new(2);
+        for _ in 0..20 {
+            let mut schedule = jobs.clone();
+            rng.shuffle(&mut schedule);
+            let keys = random_key_encode(&schedule, &jobs);
+            assert!(keys.iter().all(|k| (0.0..1.0).contains(k)));
+            assert_eq!(random_key_decode(&keys, &jobs), schedule);
+        }
+        // Only the order of the keys matters
+        let keys = [0.9, -3.0, 0.25, 0.3];
+        let schedule = random_key_decode(&keys, &jobs);
+        assert_eq!(
+            random_key_decode(&random_key_encode(&schedule, &jobs), &jobs),
+            schedule
+        );
+        assert!(random_key_decode(&[], &[]).is_empty());
+        assert!(random_key_encode(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_random_key_decode_different_keys() {
+        let jobs: Vec<FlowShopJob> = (0..4).map(|p| FlowShopJob::new(vec![p, 1])).collect();
+        let mut rng = Rng::new(3);
+        let mut schedules = Vec::new();
+        for _ in 0..50 {
+            let keys: Vec<f64> = (0..jobs.len()).map(|_| rng.next_f64()).collect();
+            schedules.push(random_key_decode(&keys, &jobs));
+        }
+        schedules.sort_by_key(|s| s.iter().map(|j| j.processing_times[0]).collect::<Vec<_>>());
+        schedules.dedup();
+        // 4! orders, 50 random keys hit far more than one of them
+        assert!(schedules.len() > 10);
+        assert_ne!(
+            random_key_decode(&[0.1, 0.2, 0.3, 0.4], &jobs),
+            random_key_decode(&[0.4, 0.3, 0.2, 0.1], &jobs)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_key_encode_not_a_permutation() {
+        let jobs = vec![FlowShopJob::new(vec![1, 2]), FlowShopJob::new(vec![3, 6])];
+        random_key_encode(&[jobs[0].clone(), jobs[0].clone()], &jobs);
+    }
+
+    #[test]
+    fn test_extract_machine_sub_problem_bounds_c_max() {
+        let schedule = vec![
+            FlowShopJob::new(vec![5, 4, 4, 2]),
+            FlowShopJob::new(vec![2, 3, 5, 6]),
+            FlowShopJob::new(vec![4, 1, 3, 7]),
+            FlowShopJob::new(vec![6, 6, 2, 1]),
+            FlowShopJob::new(vec![1, 7, 3, 3]),
+        ];
+        let c_max = flow_shop_c_max(&schedule);
+        for machine_idx in 0..4 {
+            let sub_problem = JobList::new(extract_machine_sub_problem(&schedule, machine_idx));
+            assert_eq!(sub_problem.jobs.len(), schedule.len());
+            // The same order can not be worse on a single machine
+            assert!(sub_problem.c_max() <= c_max);
+            assert!(schrage_preemptive(&sub_problem).c_max() <= c_max);
+            assert!(schrage(&sub_problem).c_max() >= schrage_preemptive(&sub_problem).c_max());
+        }
+    }
+
+    #[test]
+    fn test_extract_machine_sub_problem_edges() {
+        let jobs = vec![FlowShopJob::new(vec![5, 4, 4])];
+        assert_eq!(
+            extract_machine_sub_problem(&jobs, 0),
+            vec![Job::new(0, 5, 8)]
+        );
+        assert_eq!(
+            extract_machine_sub_problem(&jobs, 2),
+            vec![Job::new(9, 4, 0)]
+        );
+        assert!(extract_machine_sub_problem(&[], 0).is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+use crate::flow_shop::{flow_shop_c_max, FlowShopJob};
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Returns a copy of `seq` with `job_idx` inserted at position `pos`.
+///
+/// # Panics
+///
+/// Panics if `pos > seq.len()`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::neh::insert_at;
+/// assert_eq!(insert_at(&[0, 1, 2], 3, 1), vec![0, 3, 1, 2]);
+/// assert_eq!(insert_at(&[0, 1, 2], 3, 3), vec![0, 1, 2, 3]);
+/// ```
+pub fn insert_at(seq: &[usize], job_idx: usize, pos: usize) -> Vec<usize> {
+    let mut result = Vec::with_capacity(seq.len() + 1);
+    result.extend_from_slice(&seq[..pos]);
+    result.push(job_idx);
+    result.extend_from_slice(&seq[pos..]);
+    result
+}
+
+/// NEH (Nawaz, Enscore, Ham) construction heuristic for the permutation
+/// flow shop.
+///
+/// The jobs are taken by descending total processing time and each one is
+/// inserted at the position of the partial sequence that gives the smallest
+/// c max (the first such position on ties).
+///
+/// # Panics
+///
+/// Panics if the jobs do not all have the same number of machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::neh::neh;
+/// use proc_opt::flow_shop::{flow_shop_c_max, FlowShopJob};
+/// let jobs = vec![
+///     FlowShopJob::new(vec![5, 4, 4]),
+///     FlowShopJob::new(vec![2, 3, 5]),
+///     FlowShopJob::new(vec![4, 1, 3]),
+/// ];
+/// let result = neh(jobs);
+/// assert_eq!(flow_shop_c_max(&result), 18);
+/// ```
+pub fn neh(jobs: Vec<FlowShopJob>) -> Vec<FlowShopJob> {
+    let seq = neh_order(&jobs);
+    seq.iter().map(|&i| jobs[i].clone()).collect()
+}
+
+/// NEH followed by a reinsertion phase: every job in turn is taken out of
+/// the sequence and inserted back at the position that gives the smallest
+/// c max, until a pass over all the jobs improves nothing. A job is moved
+/// only if that makes the c max strictly smaller, so the result is never
+/// worse than [`neh`].
+///
+/// # Panics
+///
+/// Panics if the jobs do not all have the same number of machines.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::flow_shop::neh::{neh, neh_reinsertion};
+/// use proc_opt::flow_shop::{flow_shop_c_max, FlowShopJob};
+/// let jobs = vec![
+///     FlowShopJob::new(vec![5, 4, 4]),
+///     FlowShopJob::new(vec![2, 3, 5]),
+///     FlowShopJob::new(vec![4, 1, 3]),
+/// ];
+/// let result = neh_reinsertion(jobs.clone());
+/// assert!(flow_shop_c_max(&result) <= flow_shop_c_max(&neh(jobs)));
+/// ```
+pub fn neh_reinsertion(jobs: Vec<FlowShopJob>) -> Vec<FlowShopJob> {
+    let c_max_of = |seq: &[usize]| {
+        let schedule: Vec<FlowShopJob> = seq.iter().map(|&i| jobs[i].clone()).collect();
+        flow_shop_c_max(&schedule)
+    };
+    let mut seq = neh_order(&jobs);
+    let mut c_max = c_max_of(&seq);
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for job_idx in seq.clone() {
+            let pos = seq.iter().position(|&i| i == job_idx).unwrap();
+            let mut without = seq.clone();
+            without.remove(pos);
+            let (best, best_c_max) = (0..=without.len())
+                .map(|pos| insert_at(&without, job_idx, pos))
+                .map(|candidate| {
+                    let c_max = c_max_of(&candidate);
+                    (candidate, c_max)
+                })
+                .min_by_key(|(_, c_max)| *c_max)
+                .unwrap();
+            if best_c_max < c_max {
+                seq = best;
+                c_max = best_c_max;
+                improved = true;
+            }
+        }
+    }
+    seq.iter().map(|&i| jobs[i].clone()).collect()
+}
+
+/// Returns the NEH sequence as indices into `jobs`.
+fn neh_order(jobs: &[FlowShopJob]) -> Vec<usize> {
+    let mut by_total_time: Vec<usize> = (0..jobs.len()).collect();
+    by_total_time.sort_by_key(|&i| cmp::Reverse(jobs[i].total_processing_time()));
+
+    let c_max_of = |seq: &[usize]| {
+        let schedule: Vec<FlowShopJob> = seq.iter().map(|&i| jobs[i].clone()).collect();
+        flow_shop_c_max(&schedule)
+    };
+    let mut seq: Vec<usize> = Vec::with_capacity(jobs.len());
+    for job_idx in by_total_time {
+        seq = (0..=seq.len())
+            .map(|pos| insert_at(&seq, job_idx, pos))
+            .min_by_key(|candidate| c_max_of(candidate))
+            .unwrap();
+    }
+    seq
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow_shop::johnson::{flow_shop_cmax, johnson, TwoMachineJob};
+    use crate::rng::Rng;
+
+    #[test]
+    fn test_neh_two_machines_against_johnson() {
+        let mut rng = Rng::new(11);
+        for n in 1..15 {
+            let jobs: Vec<TwoMachineJob> = (0..n)
+                .map(|id| TwoMachineJob::new(id, rng.gen_range(1, 30), rng.gen_range(1, 30)))
+                .collect();
+            let flow_shop_jobs: Vec<FlowShopJob> = jobs
+                .iter()
+                .map(|j| FlowShopJob::new(vec![j.p1, j.p2]))
+                .collect();
+            let result = neh(flow_shop_jobs);
+            assert_eq!(result.len(), n);
+            // Johnson's algorithm is optimal for two machines
+            let optimum = flow_shop_cmax(&johnson(jobs));
+            assert!(flow_shop_c_max(&result) >= optimum);
+            assert!(flow_shop_c_max(&result) * 10 <= optimum * 11);
+        }
+    }
+
+    #[test]
+    fn test_neh_is_permutation() {
+        let jobs = vec![
+            FlowShopJob::new(vec![5, 4, 4, 2]),
+            FlowShopJob::new(vec![2, 3, 5, 6]),
+            FlowShopJob::new(vec![4, 1, 3, 7]),
+            FlowShopJob::new(vec![6, 6, 2, 1]),
+            FlowShopJob::new(vec![1, 7, 3, 3]),
+        ];
+        let result = neh(jobs.clone());
+        assert_eq!(result.len(), jobs.len());
+        for job in &jobs {
+            assert!(result.contains(job));
+        }
+    }
+
+    #[test]
+    fn test_neh_degenerate() {
+        assert!(neh(Vec::new()).is_empty());
+        let single_job = vec![FlowShopJob::new(vec![3, 4, 5])];
+        assert_eq!(neh(single_job.clone()), single_job);
+        let single_machine = vec![
+            FlowShopJob::new(vec![3]),
+            FlowShopJob::new(vec![4]),
+            FlowShopJob::new(vec![1]),
+        ];
+        assert_eq!(flow_shop_c_max(&neh(single_machine)), 8);
+    }
+
+    #[test]
+    fn test_neh_reinsertion_not_worse_than_neh() {
+        let mut rng = Rng::new(5);
+        let mut improved = 0;
+        for _ in 0..100 {
+            let n = rng.gen_range(0, 10) as usize;
+            let m = rng.gen_range(1, 5) as usize;
+            let jobs: Vec<FlowShopJob> = (0..n)
+                .map(|_| FlowShopJob::new((0..m).map(|_| rng.gen_range(1, 20)).collect()))
+                .collect();
+            let result = neh_reinsertion(jobs.clone());
+            assert_eq!(result.len(), jobs.len());
+            for job in &jobs {
+                assert!(result.contains(job));
+            }
+            let neh_c_max = flow_shop_c_max(&neh(jobs));
+            assert!(flow_shop_c_max(&result) <= neh_c_max);
+            if flow_shop_c_max(&result) < neh_c_max {
+                improved += 1;
+            }
+        }
+        // The reinsertion is not a no-op
+        assert!(improved > 0);
+    }
+}
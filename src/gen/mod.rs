@@ -0,0 +1,159 @@
+use crate::rng::Rng;
+use crate::schrage::jobs::Job;
+use alloc::vec::Vec;
+
+/// Ranges of the random jobs made by [`generate_jobs`], every bound
+/// is inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenConfig {
+    pub r_max: u32,
+    pub p_max: u32,
+    pub q_max: u32,
+    pub r_min: u32,
+    pub p_min: u32,
+    pub q_min: u32,
+}
+
+/// Returns `n` random jobs, with every field drawn uniformly
+/// from its range in `config`.
+///
+/// The same seed always gives the same jobs.
+///
+/// # Panics
+///
+/// Panics if a minimum of `config` is greater than its maximum.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::gen::{generate_jobs, GenConfig};
+/// let config = GenConfig { r_max: 50, p_max: 10, q_max: 50, r_min: 0, p_min: 1, q_min: 0 };
+/// let jobs = generate_jobs(20, config, 7);
+/// assert_eq!(jobs.len(), 20);
+/// assert!(jobs.iter().all(|job| (1..=10).contains(&job.processing_time)));
+/// assert_eq!(jobs, generate_jobs(20, config, 7));
+/// ```
+pub fn generate_jobs(n: usize, config: GenConfig, seed: u64) -> Vec<Job> {
+    let mut rng = Rng::new(seed);
+    (0..n)
+        .map(|_| {
+            Job::new(
+                rng.gen_range(config.r_min, config.r_max),
+                rng.gen_range(config.p_min, config.p_max),
+                rng.gen_range(config.q_min, config.q_max),
+            )
+        })
+        .collect()
+}
+
+/// Returns `n` random jobs on which the preemptive Schrage schedule
+/// interrupts jobs often.
+///
+/// Half of the jobs are long with short cooldown times and are all delivered
+/// early, the other half are short with long cooldown times and are
+/// delivered over a much longer period, so they keep arriving while a long
+/// job runs and take the machine over from it.
+///
+/// The same seed always gives the same jobs.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::gen::generate_tight_instance;
+/// use proc_opt::schrage::jobs::JobList;
+/// use proc_opt::schrage::schrage_preemptive;
+/// let jobs = JobList::new(generate_tight_instance(40, 3));
+/// assert!(schrage_preemptive(&jobs).number_of_preemptions() > 0);
+/// ```
+pub fn generate_tight_instance(n: usize, seed: u64) -> Vec<Job> {
+    let mut rng = Rng::new(seed);
+    let horizon = n as u32 * 15;
+    (0..n)
+        .map(|i| {
+            if i % 2 == 0 {
+                Job::new(
+                    rng.gen_range(0, horizon / 10),
+                    rng.gen_range(20, 40),
+                    rng.gen_range(0, 20),
+                )
+            } else {
+                Job::new(
+                    rng.gen_range(0, horizon),
+                    rng.gen_range(1, 5),
+                    rng.gen_range(50, 100),
+                )
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schrage::jobs::JobList;
+    use crate::schrage::schrage_preemptive;
+
+    const CONFIG: GenConfig = GenConfig {
+        r_max: 100,
+        p_max: 20,
+        q_max: 100,
+        r_min: 0,
+        p_min: 1,
+        q_min: 0,
+    };
+
+    #[test]
+    fn test_generate_jobs_same_seed() {
+        assert_eq!(generate_jobs(30, CONFIG, 5), generate_jobs(30, CONFIG, 5));
+        assert_eq!(
+            generate_tight_instance(30, 5),
+            generate_tight_instance(30, 5)
+        );
+    }
+
+    #[test]
+    fn test_generate_jobs_different_seeds() {
+        for seed in 0..10 {
+            assert_ne!(
+                generate_jobs(30, CONFIG, seed),
+                generate_jobs(30, CONFIG, seed + 1)
+            );
+            assert_ne!(
+                generate_tight_instance(30, seed),
+                generate_tight_instance(30, seed + 1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_jobs_ranges() {
+        let config = GenConfig {
+            r_max: 10,
+            p_max: 5,
+            q_max: 30,
+            r_min: 10,
+            p_min: 2,
+            q_min: 20,
+        };
+        let jobs = generate_jobs(100, config, 0);
+        assert_eq!(jobs.len(), 100);
+        for job in &jobs {
+            assert_eq!(job.delivery_time, 10);
+            assert!((2..=5).contains(&job.processing_time));
+            assert!((20..=30).contains(&job.cooldown_time));
+        }
+        assert!(generate_jobs(0, config, 0).is_empty());
+        assert!(generate_tight_instance(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_generate_tight_instance_preemptions() {
+        for seed in 0..10 {
+            let tight = JobList::new(generate_tight_instance(40, seed));
+            let uniform = JobList::new(generate_jobs(40, CONFIG, seed));
+            let preemptions = schrage_preemptive(&tight).number_of_preemptions();
+            assert!(preemptions >= 10);
+            assert!(preemptions > schrage_preemptive(&uniform).number_of_preemptions());
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::schrage::jobs::{Job, JobList};
+use std::io::{Read, Write};
+use std::{error, fmt, io, num};
+
+/// Header row of the CSV format.
+const HEADER: &str = "r,p,q";
+
+/// Names of the fields of a row, in order.
+const FIELDS: [&str; 3] = ["r", "p", "q"];
+
+/// Error of the CSV reader and writer of [`JobList`].
+///
+/// Line numbers start at 1 and count the header row.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The first row is not `r,p,q`.
+    InvalidHeader,
+    /// A row with fewer than three fields.
+    MissingField { line: usize, field: &'static str },
+    /// A row with more than three fields.
+    TooManyFields { line: usize },
+    /// A field that is not a valid `u32`.
+    Parse {
+        line: usize,
+        field: &'static str,
+        source: num::ParseIntError,
+    },
+    /// Reading or writing failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::InvalidHeader => write!(f, "the header row must be `{}`", HEADER),
+            CsvError::MissingField { line, field } => {
+                write!(f, "line {}: missing field `{}`", line, field)
+            }
+            CsvError::TooManyFields { line } => write!(f, "line {}: too many fields", line),
+            CsvError::Parse {
+                line,
+                field,
+                source,
+            } => write!(f, "line {}: invalid field `{}`: {}", line, field, source),
+            CsvError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CsvError::Parse { source, .. } => Some(source),
+            CsvError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> CsvError {
+        CsvError::Io(e)
+    }
+}
+
+impl JobList {
+    /// Reads a [`JobList`] from CSV with a `r,p,q` header row and one row
+    /// per job. Whitespace around fields and empty lines are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let csv = "r,p,q\n10,5,7\n0,6,17\n";
+    /// let jobs = JobList::from_csv(csv.as_bytes()).unwrap();
+    /// assert_eq!(jobs.jobs, vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]);
+    /// ```
+    pub fn from_csv(mut reader: impl Read) -> Result<JobList, CsvError> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        JobList::from_csv_str(&s)
+    }
+
+    /// Reads a [`JobList`] from a CSV string, see [`JobList::from_csv`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let jobs = JobList::from_csv_str("r,p,q\n10,5,7").unwrap();
+    /// assert_eq!(jobs.jobs, vec![Job::new(10, 5, 7)]);
+    /// assert!(JobList::from_csv_str("r,p,q\n10,5").is_err());
+    /// ```
+    pub fn from_csv_str(s: &str) -> Result<JobList, CsvError> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+        match lines.next() {
+            Some((_, header)) if header.replace(' ', "") == HEADER => {}
+            _ => return Err(CsvError::InvalidHeader),
+        }
+
+        let mut jobs = Vec::new();
+        for (line, row) in lines {
+            let mut fields = row.split(',').map(str::trim);
+            let mut values = [0; 3];
+            for (value, field) in values.iter_mut().zip(FIELDS) {
+                let text = fields
+                    .next()
+                    .ok_or(CsvError::MissingField { line, field })?;
+                *value = text.parse().map_err(|source| CsvError::Parse {
+                    line,
+                    field,
+                    source,
+                })?;
+            }
+            if fields.next().is_some() {
+                return Err(CsvError::TooManyFields { line });
+            }
+            jobs.push(Job::new(values[0], values[1], values[2]));
+        }
+        Ok(JobList::new(jobs))
+    }
+
+    /// Writes this [`JobList`] as CSV with a `r,p,q` header row
+    /// and one row per job.
+    pub fn to_csv(&self, mut writer: impl Write) -> Result<(), CsvError> {
+        writer.write_all(self.to_csv_string().as_bytes())?;
+        Ok(())
+    }
+
+    /// Returns this [`JobList`] as CSV, see [`JobList::to_csv`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let jobs = JobList::new(vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]);
+    /// assert_eq!(jobs.to_csv_string(), "r,p,q\n10,5,7\n0,6,17\n");
+    /// ```
+    pub fn to_csv_string(&self) -> String {
+        let mut csv = format!("{}\n", HEADER);
+        for job in &self.jobs {
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                job.delivery_time, job.processing_time, job.cooldown_time
+            ));
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_instances;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_csv_round_trip() {
+        for jobs in test_instances() {
+            let original = JobList::new(jobs);
+            let mut buffer = Vec::new();
+            original.to_csv(&mut buffer).unwrap();
+            let mut again = Vec::new();
+            JobList::from_csv(Cursor::new(&buffer))
+                .and_then(|jl| jl.to_csv(&mut again))
+                .unwrap();
+            assert_eq!(buffer, again);
+            assert_eq!(JobList::from_csv(Cursor::new(&buffer)).unwrap(), original);
+            let s = original.to_csv_string();
+            assert_eq!(JobList::from_csv_str(&s).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn test_from_csv_str_whitespace() {
+        let csv = " r, p, q \r\n\n 10 , 5,7\r\n0,6,17\n\n";
+        assert_eq!(
+            JobList::from_csv_str(csv).unwrap().jobs,
+            vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]
+        );
+    }
+
+    #[test]
+    fn test_from_csv_str_errors() {
+        assert!(matches!(
+            JobList::from_csv_str(""),
+            Err(CsvError::InvalidHeader)
+        ));
+        assert!(matches!(
+            JobList::from_csv_str("10,5,7\n"),
+            Err(CsvError::InvalidHeader)
+        ));
+        assert!(matches!(
+            JobList::from_csv_str("r,p,q\n10,5,7\n10,5\n"),
+            Err(CsvError::MissingField {
+                line: 3,
+                field: "q"
+            })
+        ));
+        assert!(matches!(
+            JobList::from_csv_str("r,p,q\n10,x,7\n"),
+            Err(CsvError::Parse {
+                line: 2,
+                field: "p",
+                ..
+            })
+        ));
+        assert!(matches!(
+            JobList::from_csv_str("r,p,q\n10,5,7,1\n"),
+            Err(CsvError::TooManyFields { line: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_from_csv_io_error() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+        }
+        assert!(matches!(JobList::from_csv(Failing), Err(CsvError::Io(_))));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::io::ParseError;
+use crate::schrage::jobs::Job;
+
+/// Returns the jobs as a JSON array of `{"r": .., "p": .., "q": ..}` objects.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::io::json::jobs_to_json_manual;
+/// assert_eq!(
+///     jobs_to_json_manual(&[Job::new(10, 5, 7), Job::new(0, 6, 17)]),
+///     r#"[{"r": 10, "p": 5, "q": 7}, {"r": 0, "p": 6, "q": 17}]"#
+/// );
+/// ```
+pub fn jobs_to_json_manual(jobs: &[Job]) -> String {
+    let objects: Vec<String> = jobs
+        .iter()
+        .map(|job| {
+            format!(
+                r#"{{"r": {}, "p": {}, "q": {}}}"#,
+                job.delivery_time, job.processing_time, job.cooldown_time
+            )
+        })
+        .collect();
+    format!("[{}]", objects.join(", "))
+}
+
+/// Parses a JSON array of `{"r": .., "p": .., "q": ..}` objects, the format
+/// of [`jobs_to_json_manual`].
+///
+/// Only this subset of JSON is supported: the keys may come in any order and
+/// whitespace is free, but every object must have exactly the three keys with
+/// non-negative integer values.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::io::json::jobs_from_json_manual;
+/// let jobs = jobs_from_json_manual(r#"[{"q": 7, "r": 10, "p": 5}]"#).unwrap();
+/// assert_eq!(jobs, vec![Job::new(10, 5, 7)]);
+/// assert!(jobs_from_json_manual(r#"[{"r": 10, "p": 5}]"#).is_err());
+/// ```
+pub fn jobs_from_json_manual(s: &str) -> Result<Vec<Job>, ParseError> {
+    let mut parser = Parser {
+        input: s.as_bytes(),
+        position: 0,
+    };
+    let mut jobs = Vec::new();
+    parser.expect(b'[')?;
+    if parser.peek()? == b']' {
+        parser.position += 1;
+    } else {
+        loop {
+            jobs.push(parser.job()?);
+            match parser.next()? {
+                b',' => continue,
+                b']' => break,
+                _ => return Err(parser.unexpected()),
+            }
+        }
+    }
+    if parser.peek().is_ok() {
+        return Err(ParseError::UnexpectedCharacter {
+            position: parser.position,
+        });
+    }
+    Ok(jobs)
+}
+
+/// Cursor over the input of [`jobs_from_json_manual`].
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// Returns the next character that is not whitespace, without consuming it.
+    fn peek(&mut self) -> Result<u8, ParseError> {
+        while self.position < self.input.len() && self.input[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+        self.input
+            .get(self.position)
+            .copied()
+            .ok_or(ParseError::UnexpectedEnd)
+    }
+
+    /// Consumes the next character that is not whitespace.
+    fn next(&mut self) -> Result<u8, ParseError> {
+        let c = self.peek()?;
+        self.position += 1;
+        Ok(c)
+    }
+
+    /// Error for the character just consumed.
+    fn unexpected(&self) -> ParseError {
+        ParseError::UnexpectedCharacter {
+            position: self.position - 1,
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), ParseError> {
+        if self.next()? != expected {
+            return Err(self.unexpected());
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<u32, ParseError> {
+        self.peek()?;
+        let digits_start = self.position;
+        while self.position < self.input.len() && self.input[self.position].is_ascii_digit() {
+            self.position += 1;
+        }
+        if self.position == digits_start {
+            return Err(ParseError::UnexpectedCharacter {
+                position: digits_start,
+            });
+        }
+        // Only ASCII digits, always valid UTF-8
+        let digits = std::str::from_utf8(&self.input[digits_start..self.position]).unwrap();
+        digits.parse().map_err(|source| ParseError::ParseIntError {
+            position: digits_start,
+            source,
+        })
+    }
+
+    /// Parses one `{"r": .., "p": .., "q": ..}` object.
+    fn job(&mut self) -> Result<Job, ParseError> {
+        let mut fields: [Option<u32>; 3] = [None; 3];
+        self.expect(b'{')?;
+        loop {
+            self.expect(b'"')?;
+            let key = self.input.get(self.position..self.position + 2);
+            let index = match key {
+                Some(b"r\"") => 0,
+                Some(b"p\"") => 1,
+                Some(b"q\"") => 2,
+                _ => {
+                    return Err(ParseError::UnexpectedCharacter {
+                        position: self.position,
+                    })
+                }
+            };
+            if fields[index].is_some() {
+                // Duplicate key
+                return Err(ParseError::UnexpectedCharacter {
+                    position: self.position,
+                });
+            }
+            self.position += 2;
+            self.expect(b':')?;
+            fields[index] = Some(self.number()?);
+            match self.next()? {
+                b',' => continue,
+                b'}' => break,
+                _ => return Err(self.unexpected()),
+            }
+        }
+        match fields {
+            [Some(r), Some(p), Some(q)] => Ok(Job::new(r, p, q)),
+            [None, _, _] => Err(ParseError::MissingField { field: "r" }),
+            [_, None, _] => Err(ParseError::MissingField { field: "p" }),
+            _ => Err(ParseError::MissingField { field: "q" }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_instances;
+
+    #[test]
+    fn test_json_round_trip() {
+        for jobs in test_instances() {
+            let json = jobs_to_json_manual(&jobs);
+            assert_eq!(jobs_from_json_manual(&json), Ok(jobs));
+        }
+    }
+
+    #[test]
+    fn test_jobs_from_json_manual_whitespace() {
+        let json = "\n[ {\"p\" :5,\n \"q\": 7 , \"r\":10 } ,{\"r\":0,\"p\":6,\"q\":17}]\n";
+        assert_eq!(
+            jobs_from_json_manual(json),
+            Ok(vec![Job::new(10, 5, 7), Job::new(0, 6, 17)])
+        );
+        assert_eq!(jobs_from_json_manual(" [ ] "), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_jobs_from_json_manual_errors() {
+        assert_eq!(jobs_from_json_manual(""), Err(ParseError::UnexpectedEnd));
+        assert_eq!(
+            jobs_from_json_manual("[{\"r\": 1, \"p\": 2"),
+            Err(ParseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            jobs_from_json_manual("[{\"r\": 1, \"q\": 2}]"),
+            Err(ParseError::MissingField { field: "p" })
+        );
+        assert_eq!(
+            jobs_from_json_manual("[{\"r\": 1, \"x\": 2}]"),
+            Err(ParseError::UnexpectedCharacter { position: 11 })
+        );
+        assert_eq!(
+            jobs_from_json_manual("[{\"r\": 1, \"r\": 2}]"),
+            Err(ParseError::UnexpectedCharacter { position: 11 })
+        );
+        assert_eq!(
+            jobs_from_json_manual("[{\"r\": -1}]"),
+            Err(ParseError::UnexpectedCharacter { position: 7 })
+        );
+        assert!(matches!(
+            jobs_from_json_manual("[{\"r\": 99999999999}]"),
+            Err(ParseError::ParseIntError { position: 7, .. })
+        ));
+        assert_eq!(
+            jobs_from_json_manual("[] x"),
+            Err(ParseError::UnexpectedCharacter { position: 3 })
+        );
+    }
+}
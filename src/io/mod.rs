@@ -0,0 +1,208 @@
+pub mod csv;
+pub mod json;
+pub mod or_library;
+pub mod text;
+
+use crate::schrage::jobs::Job;
+use std::fmt::Write;
+use std::{error, fmt, num};
+
+/// Error of the parsers of this module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A character that is not allowed at this byte offset of the input.
+    UnexpectedCharacter { position: usize },
+    /// The input ends before the value is complete.
+    UnexpectedEnd,
+    /// A job without one of its fields.
+    MissingField { field: &'static str },
+    /// A number that is not a valid `u32`, at this byte offset of the input.
+    ParseIntError {
+        position: usize,
+        source: num::ParseIntError,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter { position } => {
+                write!(f, "unexpected character at position {}", position)
+            }
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParseError::MissingField { field } => write!(f, "missing field `{}`", field),
+            ParseError::ParseIntError { position, source } => {
+                write!(f, "invalid number at position {}: {}", position, source)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseError::ParseIntError { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Returns a CP Optimizer model of the single machine problem with
+/// delivery and cooldown times (1|r_j,q_j|Cmax) in OPL syntax.
+///
+/// Every job is an interval variable `job<i>` (numbered from 1) that can not
+/// start before its delivery time, the intervals may not overlap and the
+/// makespan is the latest end of a job plus its cooldown time.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::io::to_cp_optimizer_model;
+/// let model = to_cp_optimizer_model(&[Job::new(0, 6, 17), Job::new(10, 5, 7)]);
+/// assert!(model.contains("dvar interval job1 in 0..maxint size 6;"));
+/// assert!(model.contains("dexpr int makespan = max(endOf(job1) + 17, endOf(job2) + 7);"));
+/// ```
+pub fn to_cp_optimizer_model(jobs: &[Job]) -> String {
+    let names: Vec<String> = (1..=jobs.len()).map(|i| format!("job{}", i)).collect();
+    let mut model = String::from("using CP;\n\n");
+    for (name, job) in names.iter().zip(jobs) {
+        writeln!(
+            model,
+            "dvar interval {} in {}..maxint size {};",
+            name, job.delivery_time, job.processing_time
+        )
+        .unwrap();
+    }
+    writeln!(model, "dvar sequence machine in [{}];\n", names.join(", ")).unwrap();
+
+    let ends: Vec<String> = names
+        .iter()
+        .zip(jobs)
+        .map(|(name, job)| format!("endOf({}) + {}", name, job.cooldown_time))
+        .collect();
+    match ends.len() {
+        0 => model.push_str("dexpr int makespan = 0;\n\n"),
+        1 => writeln!(model, "dexpr int makespan = {};\n", ends[0]).unwrap(),
+        _ => writeln!(model, "dexpr int makespan = max({});\n", ends.join(", ")).unwrap(),
+    }
+
+    model.push_str("minimize makespan;\n\nsubject to {\n  noOverlap(machine);\n}\n");
+    model
+}
+
+/// Instances of the tests of the whole crate, for round trip tests.
+#[cfg(test)]
+pub(crate) fn test_instances() -> Vec<Vec<Job>> {
+    vec![
+        vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ],
+        vec![
+            Job::new(1, 5, 9), // 1
+            Job::new(4, 5, 4), // 2
+            Job::new(1, 4, 6), // 3
+            Job::new(7, 3, 3), // 4
+            Job::new(3, 6, 8), // 5
+            Job::new(4, 7, 1), // 6
+        ],
+        vec![
+            Job::new(162, 52, 241), // 1
+            Job::new(103, 68, 470), // 2
+            Job::new(39, 38, 340),  // 3
+            Job::new(394, 34, 400), // 4
+            Job::new(15, 86, 700),  // 5
+            Job::new(144, 73, 536), // 6
+            Job::new(51, 52, 403),  // 7
+            Job::new(233, 68, 23),  // 8
+            Job::new(183, 17, 641), // 9
+            Job::new(728, 18, 640), // 10
+            Job::new(667, 80, 92),  // 11
+            Job::new(57, 21, 76),   // 12
+            Job::new(35, 37, 386),  // 13
+            Job::new(567, 71, 618), // 14
+            Job::new(226, 5, 629),  // 15
+            Job::new(162, 80, 575), // 16
+            Job::new(588, 45, 632), // 17
+            Job::new(556, 23, 79),  // 18
+            Job::new(715, 8, 93),   // 19
+            Job::new(598, 45, 200), // 20
+        ],
+        vec![
+            Job::new(52, 1, 56),   // 1
+            Job::new(70, 4, 93),   // 2
+            Job::new(112, 22, 79), // 3
+            Job::new(5, 14, 125),  // 4
+            Job::new(8, 16, 114),  // 5
+            Job::new(71, 7, 71),   // 6
+            Job::new(90, 2, 13),   // 7
+            Job::new(2, 20, 88),   // 8
+            Job::new(52, 20, 56),  // 9
+            Job::new(9, 28, 94),   // 10
+        ],
+        vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ],
+        vec![
+            Job::new(219, 5, 276),
+            Job::new(84, 13, 103),
+            Job::new(336, 35, 146),
+            Job::new(271, 62, 264),
+            Job::new(120, 33, 303),
+            Job::new(299, 14, 328),
+            Job::new(106, 46, 91),
+            Job::new(181, 93, 97),
+            Job::new(263, 13, 168),
+            Job::new(79, 60, 235),
+        ],
+        vec![Job::new(3, 4, 5)],
+        Vec::new(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_cp_optimizer_model() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let model = to_cp_optimizer_model(&jobs);
+        assert_eq!(model.matches("dvar interval ").count(), 7);
+        assert!(model.contains("dvar interval job7 in 30..maxint size 2;"));
+        assert!(model.contains(
+            "dexpr int makespan = max(endOf(job1) + 7, endOf(job2) + 26, endOf(job3) + 24, \
+             endOf(job4) + 21, endOf(job5) + 8, endOf(job6) + 17, endOf(job7) + 0);"
+        ));
+        assert!(model.contains("minimize makespan;"));
+        assert!(model.contains("noOverlap(machine);"));
+    }
+
+    #[test]
+    fn test_to_cp_optimizer_model_small() {
+        let model = to_cp_optimizer_model(&[Job::new(3, 4, 5)]);
+        assert_eq!(model.matches("dvar interval ").count(), 1);
+        assert!(model.contains("dexpr int makespan = endOf(job1) + 5;"));
+
+        let model = to_cp_optimizer_model(&[]);
+        assert_eq!(model.matches("dvar interval ").count(), 0);
+        assert!(model.contains("dexpr int makespan = 0;"));
+    }
+}
@@ -0,0 +1,197 @@
+use crate::io::text::{parse_field, parse_line, ParseError};
+use crate::schrage::jobs::Job;
+use std::io::{self, BufRead, Write};
+
+/// The 20 job instance the tests of the crate use, in the instance file
+/// format. It is not one of the OR-Library instances. Its optimal c max is
+/// 1386, Schrage gives 1399.
+pub const TEST_INSTANCE_20: &str = "20
+162 52 241
+103 68 470
+39 38 340
+394 34 400
+15 86 700
+144 73 536
+51 52 403
+233 68 23
+183 17 641
+728 18 640
+667 80 92
+57 21 76
+35 37 386
+567 71 618
+226 5 629
+162 80 575
+588 45 632
+556 23 79
+715 8 93
+598 45 200
+";
+
+/// Reads a single machine instance with delivery and cooldown times in
+/// the format of the OR-Library style instance files: the number of jobs `n`
+/// on the first line, then `n` lines `r p q`. Blank lines are ignored.
+///
+/// # Errors
+///
+/// Returns the errors of the text format of
+/// [`JobList`](crate::schrage::jobs::JobList), with
+/// [`ParseError::JobCount`] if the number of job lines is not `n` and
+/// [`ParseError::TooFewFields`] on line 1 for an empty input.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::io::or_library::read_or_library_instance;
+/// use proc_opt::schrage::jobs::Job;
+/// let jobs = read_or_library_instance("2\n10 5 7\n0 6 17\n".as_bytes()).unwrap();
+/// assert_eq!(jobs, vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]);
+/// assert!(read_or_library_instance("3\n10 5 7\n".as_bytes()).is_err());
+/// ```
+pub fn read_or_library_instance(reader: impl BufRead) -> Result<Vec<Job>, ParseError> {
+    let mut expected = None;
+    let mut jobs = Vec::new();
+    for (i, text) in reader.lines().enumerate() {
+        let text = text?;
+        let line = i + 1;
+        if expected.is_none() {
+            let mut fields = text.split_whitespace();
+            let n = match fields.next() {
+                Some(n) => n,
+                None => continue,
+            };
+            expected = Some(parse_field(line, "n", n)?);
+            if fields.next().is_some() {
+                return Err(ParseError::TooManyFields { line });
+            }
+        } else if let Some(job) = parse_line(line, &text)? {
+            jobs.push(job);
+        }
+    }
+    match expected {
+        None => Err(ParseError::TooFewFields { line: 1 }),
+        Some(expected) if expected != jobs.len() => Err(ParseError::JobCount {
+            expected,
+            found: jobs.len(),
+        }),
+        Some(_) => Ok(jobs),
+    }
+}
+
+/// Writes jobs in the format read by [`read_or_library_instance`].
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::io::or_library::write_or_library_instance;
+/// use proc_opt::schrage::jobs::Job;
+/// let mut out = Vec::new();
+/// write_or_library_instance(&[Job::new(10, 5, 7)], &mut out).unwrap();
+/// assert_eq!(out, b"1\n10 5 7\n");
+/// ```
+pub fn write_or_library_instance(jobs: &[Job], mut writer: impl Write) -> Result<(), io::Error> {
+    writeln!(writer, "{}", jobs.len())?;
+    for job in jobs {
+        writeln!(
+            writer,
+            "{} {} {}",
+            job.delivery_time, job.processing_time, job.cooldown_time
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::carlier::carlier;
+    use crate::io::test_instances;
+    use crate::rng::Rng;
+    use crate::schrage::jobs::JobList;
+    use crate::schrage::{schrage, schrage_preemptive};
+
+    #[test]
+    fn test_read_test_instance_20() {
+        let jobs = read_or_library_instance(TEST_INSTANCE_20.as_bytes()).unwrap();
+        assert_eq!(jobs.len(), 20);
+        assert_eq!(jobs[0], Job::new(162, 52, 241));
+        let js = JobList::new(jobs.clone());
+        // The preemptive lower bound is reached by the optimum
+        assert_eq!(schrage_preemptive(&js).c_max(), 1386);
+        assert_eq!(schrage(&js).c_max(), 1399);
+        assert_eq!(carlier(jobs).1, 1386);
+    }
+
+    #[test]
+    fn test_or_library_round_trip() {
+        for jobs in test_instances() {
+            let mut buffer = Vec::new();
+            write_or_library_instance(&jobs, &mut buffer).unwrap();
+            assert_eq!(read_or_library_instance(buffer.as_slice()).unwrap(), jobs);
+        }
+        let mut buffer = Vec::new();
+        write_or_library_instance(&[], &mut buffer).unwrap();
+        assert!(read_or_library_instance(buffer.as_slice())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_read_or_library_instance_errors() {
+        assert!(matches!(
+            read_or_library_instance("".as_bytes()),
+            Err(ParseError::TooFewFields { line: 1 })
+        ));
+        assert!(matches!(
+            read_or_library_instance("x\n".as_bytes()),
+            Err(ParseError::InvalidField {
+                line: 1,
+                field: "n"
+            })
+        ));
+        assert!(matches!(
+            read_or_library_instance("99999999999999999999999\n".as_bytes()),
+            Err(ParseError::ParseIntError { line: 1, .. })
+        ));
+        assert!(matches!(
+            read_or_library_instance("1\n10 x 7\n".as_bytes()),
+            Err(ParseError::InvalidField {
+                line: 2,
+                field: "p"
+            })
+        ));
+        assert!(matches!(
+            read_or_library_instance("1 2\n".as_bytes()),
+            Err(ParseError::TooManyFields { line: 1 })
+        ));
+        assert!(matches!(
+            read_or_library_instance("1\n10 5 7\n1 2 3\n".as_bytes()),
+            Err(ParseError::JobCount {
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            read_or_library_instance("\n1\n\n10 5\n".as_bytes()),
+            Err(ParseError::TooFewFields { line: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_read_or_library_instance_random_bytes() {
+        // Arbitrary input must give an error or jobs, never a panic
+        let mut rng = Rng::new(11);
+        let alphabet = b"0123456789 \n\t-x#";
+        for _ in 0..2000 {
+            let len = rng.gen_index(40);
+            let bytes: Vec<u8> = (0..len)
+                .map(|_| alphabet[rng.gen_index(alphabet.len())])
+                .collect();
+            if let Ok(jobs) = read_or_library_instance(bytes.as_slice()) {
+                let mut buffer = Vec::new();
+                write_or_library_instance(&jobs, &mut buffer).unwrap();
+                assert_eq!(read_or_library_instance(buffer.as_slice()).unwrap(), jobs);
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::schrage::jobs::{Job, JobList};
+use std::io::{Read, Write};
+use std::str::FromStr;
+use std::{error, fmt, io, num};
+
+/// Names of the fields of a line, in order.
+const FIELDS: [&str; 3] = ["r", "p", "q"];
+
+/// Error of the text reader and writer of [`JobList`].
+///
+/// Line numbers start at 1 and count blank and comment lines.
+#[derive(Debug)]
+pub enum ParseError {
+    /// A field that is not a non-negative integer.
+    InvalidField { line: usize, field: &'static str },
+    /// A line with fewer than three fields.
+    TooFewFields { line: usize },
+    /// A non-negative integer field that does not fit its type.
+    ParseIntError {
+        line: usize,
+        source: num::ParseIntError,
+    },
+    /// Reading or writing failed.
+    IoError(io::Error),
+    /// A line with more than three fields.
+    TooManyFields { line: usize },
+    /// A number of jobs that differs from the one the input starts with.
+    JobCount { expected: usize, found: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidField { line, field } => {
+                write!(f, "line {}: invalid field `{}`", line, field)
+            }
+            ParseError::TooFewFields { line } => write!(f, "line {}: too few fields", line),
+            ParseError::ParseIntError { line, source } => {
+                write!(f, "line {}: invalid number: {}", line, source)
+            }
+            ParseError::IoError(e) => write!(f, "{}", e),
+            ParseError::TooManyFields { line } => write!(f, "line {}: too many fields", line),
+            ParseError::JobCount { expected, found } => {
+                write!(f, "expected {} jobs, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseError::ParseIntError { source, .. } => Some(source),
+            ParseError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(e: io::Error) -> ParseError {
+        ParseError::IoError(e)
+    }
+}
+
+/// Reads a [`JobList`] from text with one line `r p q` per job, the fields
+/// separated by whitespace. Everything after a `#` is a comment, blank
+/// lines are ignored.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// let jobs: JobList = "# r p q\n10 5 7\n\n0 6 17 # first\n".parse().unwrap();
+/// assert_eq!(jobs.jobs, vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]);
+/// assert_eq!(jobs.to_string().parse::<JobList>().unwrap(), jobs);
+/// ```
+impl FromStr for JobList {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<JobList, ParseError> {
+        let mut jobs = Vec::new();
+        for (i, text) in s.lines().enumerate() {
+            if let Some(job) = parse_line(i + 1, text)? {
+                jobs.push(job);
+            }
+        }
+        Ok(JobList::new(jobs))
+    }
+}
+
+/// Parses the line number `line` of the text format, `None` if it is
+/// blank or a comment.
+pub(crate) fn parse_line(line: usize, text: &str) -> Result<Option<Job>, ParseError> {
+    let data = text.split('#').next().unwrap_or("");
+    if data.trim().is_empty() {
+        return Ok(None);
+    }
+    let mut fields = data.split_whitespace();
+    let mut values = [0; 3];
+    for (value, field) in values.iter_mut().zip(FIELDS) {
+        let text = fields.next().ok_or(ParseError::TooFewFields { line })?;
+        *value = parse_field(line, field, text)?;
+    }
+    if fields.next().is_some() {
+        return Err(ParseError::TooManyFields { line });
+    }
+    Ok(Some(Job::new(values[0], values[1], values[2])))
+}
+
+/// Parses the field `field` of the line number `line`, which must be made
+/// of ASCII digits only.
+pub(crate) fn parse_field<T>(line: usize, field: &'static str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = num::ParseIntError>,
+{
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::InvalidField { line, field });
+    }
+    text.parse()
+        .map_err(|source| ParseError::ParseIntError { line, source })
+}
+
+impl JobList {
+    /// Reads a [`JobList`] in the text format of [`JobList::from_str`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let jobs = JobList::from_reader("10 5 7\n".as_bytes()).unwrap();
+    /// assert_eq!(jobs.jobs, vec![Job::new(10, 5, 7)]);
+    /// ```
+    pub fn from_reader(mut reader: impl Read) -> Result<JobList, ParseError> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        s.parse()
+    }
+
+    /// Writes this [`JobList`] in the text format of [`JobList::from_str`],
+    /// the same as its [`Display`](fmt::Display).
+    pub fn to_writer(&self, mut writer: impl Write) -> Result<(), ParseError> {
+        write!(writer, "{}", self)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_instances;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_text_round_trip() {
+        for jobs in test_instances() {
+            let original = JobList::new(jobs);
+            let parsed: JobList = original.to_string().parse().unwrap();
+            assert_eq!(parsed, original);
+            assert_eq!(parsed.to_string().parse::<JobList>().unwrap(), original);
+
+            let mut buffer = Vec::new();
+            original.to_writer(&mut buffer).unwrap();
+            assert_eq!(
+                JobList::from_reader(Cursor::new(&buffer)).unwrap(),
+                original
+            );
+        }
+        assert!("".parse::<JobList>().unwrap().jobs.is_empty());
+    }
+
+    #[test]
+    fn test_from_str_comments_and_whitespace() {
+        let text = "# instance\n\n  10\t5   7 \r\n# 1 2 3\n0 6 17# no space\n   \n";
+        assert_eq!(
+            text.parse::<JobList>().unwrap().jobs,
+            vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]
+        );
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert!(matches!(
+            "10 5 7\n\n10 5\n".parse::<JobList>(),
+            Err(ParseError::TooFewFields { line: 3 })
+        ));
+        assert!(matches!(
+            "10 5 7 1\n".parse::<JobList>(),
+            Err(ParseError::TooManyFields { line: 1 })
+        ));
+        assert!(matches!(
+            "# r p q\n10 x 7\n".parse::<JobList>(),
+            Err(ParseError::InvalidField {
+                line: 2,
+                field: "p"
+            })
+        ));
+        assert!(matches!(
+            "10 5 -7\n".parse::<JobList>(),
+            Err(ParseError::InvalidField {
+                line: 1,
+                field: "q"
+            })
+        ));
+        assert!(matches!(
+            "10 5 +7\n".parse::<JobList>(),
+            Err(ParseError::InvalidField {
+                line: 1,
+                field: "q"
+            })
+        ));
+        assert!(matches!(
+            "2 3 4\n4294967296 5 7\n".parse::<JobList>(),
+            Err(ParseError::ParseIntError { line: 2, .. })
+        ));
+        assert!("1 2 3\n4294967295 5 7\n".parse::<JobList>().is_ok());
+    }
+
+    #[test]
+    fn test_from_reader_io_error() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+        }
+        assert!(matches!(
+            JobList::from_reader(Failing),
+            Err(ParseError::IoError(_))
+        ));
+    }
+}
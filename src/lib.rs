@@ -1,3 +1,25 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "ffi"), forbid(unsafe_code))]
+#![cfg_attr(feature = "ffi", deny(unsafe_code))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod bench;
+pub mod display;
+pub mod exact;
+#[cfg(all(feature = "ffi", feature = "std"))]
+#[allow(unsafe_code)]
+pub mod ffi;
+pub mod flow_shop;
+pub mod gen;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod local_search;
+#[cfg(feature = "std")]
+pub mod meta;
+pub mod parallel;
+pub mod rng;
+pub mod rules;
 pub mod schrage;
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,528 @@
+use crate::rng::Rng;
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::{schrage, schrage_order};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns the schedule after the first pairwise swap of jobs that strictly
+/// reduces the c max, or `None` if no swap does (the schedule is 2-opt
+/// locally optimal).
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::local_search::two_opt_step;
+/// let js = JobList::new(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]);
+/// let improved = two_opt_step(&js).unwrap();
+/// assert_eq!(improved.c_max(), 15);
+/// assert_eq!(two_opt_step(&improved), None);
+/// ```
+pub fn two_opt_step(schedule: &JobList) -> Option<JobList> {
+    let c_max = schedule.c_max();
+    let mut candidate = schedule.clone();
+    for i in 0..candidate.jobs.len() {
+        for j in i + 1..candidate.jobs.len() {
+            candidate.jobs.swap(i, j);
+            if candidate.c_max() < c_max {
+                return Some(candidate);
+            }
+            candidate.jobs.swap(i, j);
+        }
+    }
+    None
+}
+
+/// Improves a schedule with pairwise swaps of jobs.
+///
+/// Tries all pairs of positions and keeps every swap that strictly reduces
+/// the c max, until no swap does. The jobs are only reordered, Schrage is not
+/// run, so it is usually applied to the output of [`schrage`].
+///
+/// # Panics
+///
+/// Panics if empty job list.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::schrage;
+/// use proc_opt::local_search::two_opt_improve;
+/// let js = JobList::new(vec![
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(0, 6, 17),
+///     Job::new(30, 2, 0),
+/// ]);
+/// let result = two_opt_improve(schrage(&js).job_list);
+/// assert!(result.c_max() <= 53);
+/// ```
+pub fn two_opt_improve(schedule: JobList) -> JobList {
+    let mut best = schedule;
+    let mut best_c_max = best.c_max();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..best.jobs.len() {
+            for j in i + 1..best.jobs.len() {
+                best.jobs.swap(i, j);
+                let c_max = best.c_max();
+                if c_max < best_c_max {
+                    best_c_max = c_max;
+                    improved = true;
+                } else {
+                    best.jobs.swap(i, j);
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Iterated local search starting from the Schrage schedule.
+///
+/// The Schrage schedule is improved with pairwise swaps until no swap
+/// reduces the c max, then the best schedule found so far is randomly
+/// perturbed and improved again, `restarts` times.
+///
+/// Returns the best schedule and the best c max found after the initial
+/// descent and after every restart (`restarts + 1` values).
+///
+/// # Panics
+///
+/// Panics if empty job list.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::local_search::schrage_iterated_local_search;
+/// let jobs = vec![
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(0, 6, 17),
+///     Job::new(30, 2, 0),
+/// ];
+/// let (result, history) = schrage_iterated_local_search(jobs, 10, 0);
+/// assert_eq!(result.c_max(), 50);
+/// assert_eq!(history.len(), 11);
+/// ```
+pub fn schrage_iterated_local_search(
+    jobs: Vec<Job>,
+    restarts: usize,
+    seed: u64,
+) -> (JobList, Vec<u32>) {
+    let mut rng = Rng::new(seed);
+    let mut best = two_opt_improve(schrage(&JobList::new(jobs)).job_list);
+    let mut best_c_max = best.c_max();
+    let mut history = vec![best_c_max];
+
+    for _ in 0..restarts {
+        let mut candidate = best.clone();
+        // Perturb with a couple of random swaps
+        for _ in 0..2 {
+            let i = rng.gen_index(candidate.jobs.len());
+            let j = rng.gen_index(candidate.jobs.len());
+            candidate.jobs.swap(i, j);
+        }
+        let candidate = two_opt_improve(candidate);
+        let c_max = candidate.c_max();
+        if c_max < best_c_max {
+            best = candidate;
+            best_c_max = c_max;
+        }
+        history.push(best_c_max);
+    }
+    (best, history)
+}
+
+/// Random descent with pairwise swaps of jobs, recording every step.
+///
+/// Every step swaps two random jobs of the current schedule and keeps the
+/// swap unless it raises the c max, so the c max never grows along the
+/// trajectory. Returns the schedule and its c max after every step.
+///
+/// # Panics
+///
+/// Panics if empty job list.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::rng::Rng;
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::local_search::local_search_trajectory;
+/// let js = JobList::new(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]);
+/// let trajectory = local_search_trajectory(js, 10, &mut Rng::new(0));
+/// assert_eq!(trajectory.len(), 10);
+/// assert_eq!(trajectory.last().unwrap().1, 15);
+/// ```
+pub fn local_search_trajectory(
+    initial: JobList,
+    n_steps: usize,
+    rng: &mut Rng,
+) -> Vec<(JobList, u32)> {
+    let mut current = initial;
+    let mut current_c_max = current.c_max();
+    let mut trajectory = Vec::with_capacity(n_steps);
+    for _ in 0..n_steps {
+        let i = rng.gen_index(current.jobs.len());
+        let j = rng.gen_index(current.jobs.len());
+        current.jobs.swap(i, j);
+        let c_max = current.c_max();
+        if c_max <= current_c_max {
+            current_c_max = c_max;
+        } else {
+            current.jobs.swap(i, j);
+        }
+        trajectory.push((current.clone(), current_c_max));
+    }
+    trajectory
+}
+
+/// [`local_search_trajectory`] starting from the Schrage schedule.
+///
+/// # Panics
+///
+/// Panics if empty job list.
+pub fn schrage_local_search_trajectory(
+    jobs: Vec<Job>,
+    n_steps: usize,
+    seed: u64,
+) -> Vec<(JobList, u32)> {
+    let initial = schrage(&JobList::new(jobs)).job_list;
+    local_search_trajectory(initial, n_steps, &mut Rng::new(seed))
+}
+
+/// Schrage schedule of the jobs with random noise in `[0, spread]` added to
+/// the cooldown times the ready jobs are picked by, made of the original jobs.
+fn noisy_schrage(jobs: &JobList, spread: u32, rng: &mut Rng) -> JobList {
+    let noisy: JobList = jobs
+        .iter()
+        .map(|job| {
+            job.with_cooldown_time(job.cooldown_time.saturating_add(rng.gen_range(0, spread)))
+        })
+        .collect();
+    schrage_order(&noisy)
+        .into_iter()
+        .map(|i| jobs.jobs[i])
+        .collect()
+}
+
+/// Random search around the Schrage algorithm.
+///
+/// Runs the Schrage algorithm once as is and `n_iter` more times with
+/// random noise, up to the longest processing time, added to the cooldown
+/// times it picks the ready jobs by. This changes which job goes first
+/// out of ready jobs with close cooldown times. Every order found is
+/// evaluated with the original jobs.
+///
+/// Returns the best schedule and the best c max found after the first run
+/// and after every random one (`n_iter + 1` values).
+///
+/// # Panics
+///
+/// Panics if empty job list.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::local_search::schrage_random_search;
+/// let jobs = vec![
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(0, 6, 17),
+///     Job::new(30, 2, 0),
+/// ];
+/// let (result, history) = schrage_random_search(jobs, 50, 0);
+/// assert_eq!(history[0], 53);
+/// assert!(result.c_max() <= 53);
+/// assert_eq!(result.c_max(), *history.last().unwrap());
+/// ```
+pub fn schrage_random_search(jobs: Vec<Job>, n_iter: usize, seed: u64) -> (JobList, Vec<u32>) {
+    let mut rng = Rng::new(seed);
+    let js = JobList::new(jobs);
+    let mut best = schrage(&js).job_list;
+    let mut best_c_max = best.c_max();
+    let mut history = vec![best_c_max];
+    let spread = js.iter().map(|job| job.processing_time).max().unwrap();
+
+    for _ in 0..n_iter {
+        let candidate = noisy_schrage(&js, spread, &mut rng);
+        let c_max = candidate.c_max();
+        if c_max < best_c_max {
+            best = candidate;
+            best_c_max = c_max;
+        }
+        history.push(best_c_max);
+    }
+    (best, history)
+}
+
+/// Pool of different schedules of the same jobs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnPool {
+    pub schedules: Vec<JobList>,
+    /// Index of the schedule with the lowest c max.
+    pub best_index: usize,
+}
+
+impl ColumnPool {
+    /// Returns the schedule with the lowest c max of this [`ColumnPool`].
+    pub fn best(&self) -> &JobList {
+        &self.schedules[self.best_index]
+    }
+
+    /// Returns the highest c max of a schedule of this [`ColumnPool`].
+    pub fn worst_c_max(&self) -> u32 {
+        self.schedules.iter().map(JobList::c_max).max().unwrap()
+    }
+}
+
+/// Builds a pool of up to `size` different schedules of the jobs out of
+/// variants of the Schrage algorithm.
+///
+/// The pool starts with the Schrage schedule and its [`two_opt_improve`]
+/// descent. The other schedules come from Schrage run with random noise
+/// added to the cooldown times it picks the ready jobs by, like in
+/// [`schrage_random_search`], the noise growing from schedule to schedule.
+/// Schedules already in the pool are skipped, so the pool is smaller than
+/// `size` if few enough different ones are found.
+///
+/// # Panics
+///
+/// Panics if empty job list or `size` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::local_search::build_schedule_pool;
+/// let jobs = vec![
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(0, 6, 17),
+///     Job::new(30, 2, 0),
+/// ];
+/// let pool = build_schedule_pool(jobs, 5, 0);
+/// assert_eq!(pool.schedules.len(), 5);
+/// assert!(pool.best().c_max() <= 53);
+/// assert!(pool.best().c_max() <= pool.worst_c_max());
+/// ```
+pub fn build_schedule_pool(jobs: Vec<Job>, size: usize, seed: u64) -> ColumnPool {
+    assert!(size > 0, "the pool size must be positive");
+    let mut rng = Rng::new(seed);
+    let js = JobList::new(jobs);
+    let max_p = js.iter().map(|job| job.processing_time).max().unwrap();
+    let max_q = js.iter().map(|job| job.cooldown_time).max().unwrap();
+
+    let schrage_schedule = schrage(&js).job_list;
+    let mut schedules = vec![schrage_schedule.clone()];
+    let improved = two_opt_improve(schrage_schedule);
+    let mut candidates = vec![improved];
+    // Give up on finding new schedules after a fixed number of tries
+    let tries = 10 * size;
+    for k in 0..tries {
+        if schedules.len() == size {
+            break;
+        }
+        let candidate = match candidates.pop() {
+            Some(candidate) => candidate,
+            None => {
+                let spread = max_p.saturating_add((max_q as u64 * k as u64 / tries as u64) as u32);
+                noisy_schrage(&js, spread, &mut rng)
+            }
+        };
+        if !schedules.iter().any(|s| s.jobs == candidate.jobs) {
+            schedules.push(candidate);
+        }
+    }
+
+    let best_index = (0..schedules.len())
+        .min_by_key(|&i| schedules[i].c_max())
+        .unwrap();
+    ColumnPool {
+        schedules,
+        best_index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schrage::analysis::jobs_to_permutation;
+    use crate::test_util::{brute_force_c_max, check_metaheuristic, nonempty_test_instances};
+
+    fn instances() -> Vec<JobList> {
+        nonempty_test_instances()
+            .into_iter()
+            .map(JobList::new)
+            .collect()
+    }
+
+    #[test]
+    fn test_two_opt_improve_locally_optimal() {
+        for js in instances() {
+            let schrage_c_max = schrage(&js).c_max();
+            let result = two_opt_improve(schrage(&js).job_list);
+            assert!(jobs_to_permutation(&result, &js.jobs).is_some());
+            assert!(result.c_max() <= schrage_c_max);
+            assert_eq!(two_opt_step(&result), None);
+            if js.jobs.len() <= 8 {
+                assert!(result.c_max() >= brute_force_c_max(&js.jobs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_two_opt_step() {
+        let js = JobList::new(vec![
+            Job::new(0, 3, 0),
+            Job::new(0, 2, 20),
+            Job::new(1, 4, 10),
+        ]);
+        let mut schedule = js.clone();
+        while let Some(next) = two_opt_step(&schedule) {
+            assert!(next.c_max() < schedule.c_max());
+            schedule = next;
+        }
+        // The job with the longest cooldown time goes first
+        assert_eq!(js.c_max(), 25);
+        assert_eq!(schedule.c_max(), 22);
+    }
+
+    #[test]
+    fn test_schrage_iterated_local_search() {
+        check_metaheuristic(
+            |jobs, seed| schrage_iterated_local_search(jobs, 20, seed).0,
+            true,
+        );
+    }
+
+    #[test]
+    fn test_schrage_iterated_local_search_monotone() {
+        for js in instances() {
+            let schrage_c_max = schrage(&js).c_max();
+            let (result, history) = schrage_iterated_local_search(js.jobs, 20, 1);
+            assert_eq!(history.len(), 21);
+            assert!(history.windows(2).all(|w| w[1] <= w[0]));
+            assert!(history[0] <= schrage_c_max);
+            assert_eq!(*history.last().unwrap(), result.c_max());
+        }
+    }
+
+    #[test]
+    fn test_schrage_random_search() {
+        // Only schedules without idle time while a job is ready are tried,
+        // which misses the optimum 50 of the 7 job example
+        check_metaheuristic(|jobs, seed| schrage_random_search(jobs, 50, seed).0, false);
+    }
+
+    #[test]
+    fn test_schrage_random_search_monotone() {
+        for js in instances() {
+            let (result, history) = schrage_random_search(js.jobs.clone(), 30, 4);
+            assert_eq!(history.len(), 31);
+            assert_eq!(history[0], schrage(&js).c_max());
+            assert!(history.windows(2).all(|w| w[1] <= w[0]));
+            assert_eq!(*history.last().unwrap(), result.c_max());
+        }
+    }
+
+    #[test]
+    fn test_schrage_random_search_improves() {
+        let jobs = vec![
+            Job::new(3, 6, 9),
+            Job::new(0, 1, 6),
+            Job::new(0, 7, 15),
+            Job::new(5, 5, 7),
+            Job::new(14, 5, 9),
+        ];
+        // Schrage gives 32, the optimum is 31
+        let (result, history) = schrage_random_search(jobs.clone(), 50, 6);
+        assert_eq!(history[0], 32);
+        assert_eq!(result.c_max(), 31);
+        assert_eq!(result.c_max(), crate::exact::branch_and_bound(jobs).c_max());
+    }
+
+    #[test]
+    fn test_build_schedule_pool() {
+        for js in instances() {
+            let pool = build_schedule_pool(js.jobs.clone(), 8, 7);
+            assert!(!pool.schedules.is_empty() && pool.schedules.len() <= 8);
+            assert_eq!(pool.schedules[0], schrage(&js).job_list);
+            assert!(pool.best().c_max() <= pool.worst_c_max());
+            assert!(pool.best().c_max() <= schrage(&js).c_max());
+            for (i, schedule) in pool.schedules.iter().enumerate() {
+                assert!(pool.best().c_max() <= schedule.c_max());
+                assert!(jobs_to_permutation(schedule, &js.jobs).is_some());
+                for other in &pool.schedules[i + 1..] {
+                    assert_ne!(schedule.jobs, other.jobs);
+                }
+            }
+            assert_eq!(pool, build_schedule_pool(js.jobs, 8, 7));
+        }
+    }
+
+    #[test]
+    fn test_build_schedule_pool_large_cooldown_time() {
+        // The noisy cooldown times saturate, the c max still fits
+        let jobs = vec![
+            Job::new(0, 1, 3_000_000_000),
+            Job::new(0, 2, 5),
+            Job::new(1, 1, 7),
+        ];
+        let pool = build_schedule_pool(jobs.clone(), 5, 1);
+        assert_eq!(pool.best().c_max(), 3_000_000_001);
+        let (result, _) = schrage_random_search(
+            vec![Job::new(0, 1, u32::MAX - 1), Job::new(0, 1, u32::MAX - 5)],
+            20,
+            1,
+        );
+        assert_eq!(result.c_max(), u32::MAX);
+    }
+
+    #[test]
+    fn test_build_schedule_pool_few_schedules() {
+        // Two jobs have only two orders
+        let pool = build_schedule_pool(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)], 4, 0);
+        assert_eq!(pool.schedules.len(), 2);
+        assert_eq!(pool.best_index, 0);
+        assert_eq!(pool.best().c_max(), 15);
+        assert_eq!(pool.worst_c_max(), 20);
+    }
+
+    #[test]
+    fn test_local_search_trajectory() {
+        for js in instances() {
+            let schrage_c_max = schrage(&js).c_max();
+            let trajectory = schrage_local_search_trajectory(js.jobs.clone(), 100, 8);
+            assert_eq!(trajectory.len(), 100);
+            assert!(trajectory[0].1 <= schrage_c_max);
+            for (schedule, c_max) in &trajectory {
+                assert_eq!(schedule.c_max(), *c_max);
+                assert_eq!(schedule.jobs.len(), js.jobs.len());
+            }
+            assert!(trajectory.windows(2).all(|w| w[1].1 <= w[0].1));
+            assert_eq!(
+                trajectory,
+                schrage_local_search_trajectory(js.jobs.clone(), 100, 8)
+            );
+            assert!(local_search_trajectory(js, 0, &mut Rng::new(0)).is_empty());
+        }
+    }
+}
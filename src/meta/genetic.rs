@@ -0,0 +1,184 @@
+use crate::rng::Rng;
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::schrage_order;
+
+/// Parameters of [`genetic_algorithm`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaConfig {
+    /// Number of individuals in every generation.
+    pub population_size: usize,
+    /// Number of generations bred after the initial population.
+    pub generations: u32,
+    /// Probability that two parents are crossed over instead of copied.
+    pub crossover_rate: f64,
+    /// Probability that a child gets two of its jobs swapped.
+    pub mutation_rate: f64,
+    /// Seed of the random number generator, equal seeds give equal runs.
+    pub seed: u64,
+}
+
+/// Size of the tournaments parents are selected with.
+const TOURNAMENT_SIZE: usize = 3;
+
+/// Permutation of job indices with its c max.
+#[derive(Clone)]
+struct Individual {
+    order: Vec<usize>,
+    c_max: u32,
+}
+
+impl Individual {
+    fn new(jobs: &[Job], order: Vec<usize>) -> Individual {
+        let c_max = JobList::new(order.iter().map(|&i| jobs[i]).collect()).c_max();
+        Individual { order, c_max }
+    }
+}
+
+/// Returns the best of `TOURNAMENT_SIZE` random individuals.
+fn tournament<'a>(population: &'a [Individual], rng: &mut Rng) -> &'a Individual {
+    (0..TOURNAMENT_SIZE)
+        .map(|_| &population[rng.gen_index(population.len())])
+        .min_by_key(|individual| individual.c_max)
+        .unwrap()
+}
+
+/// Order crossover (OX): the child keeps a random slice of the first parent
+/// in place and gets the other jobs in the order of the second parent,
+/// starting after the slice.
+fn order_crossover(first: &[usize], second: &[usize], rng: &mut Rng) -> Vec<usize> {
+    let n = first.len();
+    let (mut a, mut b) = (rng.gen_index(n), rng.gen_index(n));
+    if a > b {
+        std::mem::swap(&mut a, &mut b);
+    }
+    let mut child = vec![usize::MAX; n];
+    let mut taken = vec![false; n];
+    for k in a..=b {
+        child[k] = first[k];
+        taken[first[k]] = true;
+    }
+    let mut position = (b + 1) % n;
+    for k in 0..n {
+        let gene = second[(b + 1 + k) % n];
+        if !taken[gene] {
+            child[position] = gene;
+            position = (position + 1) % n;
+        }
+    }
+    child
+}
+
+/// Genetic algorithm for the single machine problem with delivery and
+/// cooldown times.
+///
+/// Individuals are permutations of the jobs. The initial population is the
+/// Schrage schedule and random permutations. Parents are chosen by
+/// tournaments of 3, crossed over with order crossover (OX) and the children
+/// mutated by swapping two jobs. The best individual always survives to the
+/// next generation, so the result is never worse than Schrage.
+///
+/// Returns the best schedule found.
+///
+/// # Panics
+///
+/// Panics if empty job list or `population_size` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::meta::genetic::{genetic_algorithm, GaConfig};
+/// let jobs = vec![
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(0, 6, 17),
+///     Job::new(30, 2, 0),
+/// ];
+/// let config = GaConfig {
+///     population_size: 20,
+///     generations: 50,
+///     crossover_rate: 0.9,
+///     mutation_rate: 0.2,
+///     seed: 0,
+/// };
+/// let result = genetic_algorithm(jobs, config);
+/// assert!(result.c_max() <= 53);
+/// ```
+pub fn genetic_algorithm(jobs: Vec<Job>, config: GaConfig) -> JobList {
+    assert!(!jobs.is_empty(), "empty job list");
+    assert!(config.population_size > 0, "empty population");
+    let mut rng = Rng::new(config.seed);
+    let n = jobs.len();
+
+    let mut population = vec![Individual::new(
+        &jobs,
+        schrage_order(&JobList::new(jobs.clone())),
+    )];
+    while population.len() < config.population_size {
+        population.push(Individual::new(&jobs, rng.permutation(n)));
+    }
+    let mut best = population[0].clone();
+
+    for _ in 0..config.generations {
+        // Elitism
+        let mut next_generation = vec![best.clone()];
+        while next_generation.len() < config.population_size {
+            let first = tournament(&population, &mut rng);
+            let second = tournament(&population, &mut rng);
+            let mut order = if rng.next_f64() < config.crossover_rate {
+                order_crossover(&first.order, &second.order, &mut rng)
+            } else {
+                first.order.clone()
+            };
+            if rng.next_f64() < config.mutation_rate {
+                let i = rng.gen_index(n);
+                let j = rng.gen_index(n);
+                order.swap(i, j);
+            }
+            next_generation.push(Individual::new(&jobs, order));
+        }
+        population = next_generation;
+        for individual in &population {
+            if individual.c_max < best.c_max {
+                best = individual.clone();
+            }
+        }
+    }
+    JobList::new(best.order.iter().map(|&i| jobs[i]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::check_metaheuristic;
+
+    fn config(seed: u64) -> GaConfig {
+        GaConfig {
+            population_size: 30,
+            generations: 100,
+            crossover_rate: 0.9,
+            mutation_rate: 0.2,
+            seed,
+        }
+    }
+
+    #[test]
+    fn test_genetic_algorithm() {
+        check_metaheuristic(|jobs, seed| genetic_algorithm(jobs, config(seed)), true);
+    }
+
+    #[test]
+    fn test_order_crossover_is_permutation() {
+        let mut rng = Rng::new(0);
+        for _ in 0..100 {
+            let first = rng.permutation(8);
+            let second = rng.permutation(8);
+            let mut child = order_crossover(&first, &second, &mut rng);
+            child.sort_unstable();
+            assert_eq!(child, (0..8).collect::<Vec<usize>>());
+        }
+    }
+}
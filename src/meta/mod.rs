@@ -0,0 +1,2 @@
+pub mod genetic;
+pub mod simulated_annealing;
@@ -0,0 +1,145 @@
+use crate::rng::Rng;
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::schrage;
+
+/// Parameters of [`simulated_annealing`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SaConfig {
+    /// Temperature of the first iteration.
+    pub initial_temperature: f64,
+    /// Factor the temperature is multiplied by after every iteration,
+    /// usually slightly below 1.
+    pub cooling_rate: f64,
+    /// Number of neighbours tried.
+    pub iterations: u32,
+    /// Seed of the random number generator, equal seeds give equal runs.
+    pub seed: u64,
+}
+
+/// Result of [`simulated_annealing_with_history`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaResult {
+    /// Best schedule seen.
+    pub schedule: JobList,
+    /// C max of the current schedule after every iteration.
+    pub history: Vec<u32>,
+}
+
+/// Simulated annealing starting from the Schrage schedule.
+///
+/// Every iteration swaps two random jobs of the current schedule. The swap is
+/// kept if it does not increase the c max, otherwise it is kept with
+/// probability `exp(-delta / T)`, where `delta` is the increase and `T` the
+/// current temperature. The best schedule seen is returned, so the result is
+/// never worse than Schrage.
+///
+/// # Panics
+///
+/// Panics if empty job list.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::meta::simulated_annealing::{simulated_annealing, SaConfig};
+/// let jobs = vec![
+///     Job::new(10, 5, 7),
+///     Job::new(13, 6, 26),
+///     Job::new(11, 7, 24),
+///     Job::new(20, 4, 21),
+///     Job::new(30, 3, 8),
+///     Job::new(0, 6, 17),
+///     Job::new(30, 2, 0),
+/// ];
+/// let config = SaConfig {
+///     initial_temperature: 10.0,
+///     cooling_rate: 0.99,
+///     iterations: 1000,
+///     seed: 0,
+/// };
+/// let result = simulated_annealing(jobs, config);
+/// assert!(result.c_max() <= 53);
+/// ```
+pub fn simulated_annealing(jobs: Vec<Job>, config: SaConfig) -> JobList {
+    simulated_annealing_with_history(jobs, config).schedule
+}
+
+/// [`simulated_annealing`] that also returns the c max of the current
+/// schedule after every iteration, to inspect the convergence.
+///
+/// # Panics
+///
+/// Panics if empty job list.
+pub fn simulated_annealing_with_history(jobs: Vec<Job>, config: SaConfig) -> SaResult {
+    let mut rng = Rng::new(config.seed);
+    let mut current = schrage(&JobList::new(jobs)).job_list;
+    let mut current_c_max = current.c_max();
+    let mut best = current.clone();
+    let mut best_c_max = current_c_max;
+    let mut temperature = config.initial_temperature;
+    let mut history = Vec::with_capacity(config.iterations as usize);
+
+    for _ in 0..config.iterations {
+        let i = rng.gen_index(current.jobs.len());
+        let j = rng.gen_index(current.jobs.len());
+        current.jobs.swap(i, j);
+        let c_max = current.c_max();
+        let accepted = c_max <= current_c_max
+            || rng.next_f64() < (-((c_max - current_c_max) as f64) / temperature).exp();
+        if accepted {
+            current_c_max = c_max;
+            if c_max < best_c_max {
+                best = current.clone();
+                best_c_max = c_max;
+            }
+        } else {
+            current.jobs.swap(i, j);
+        }
+        history.push(current_c_max);
+        temperature *= config.cooling_rate;
+    }
+    SaResult {
+        schedule: best,
+        history,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{check_metaheuristic, nonempty_test_instances};
+
+    fn config(seed: u64) -> SaConfig {
+        SaConfig {
+            initial_temperature: 50.0,
+            cooling_rate: 0.995,
+            iterations: 2000,
+            seed,
+        }
+    }
+
+    #[test]
+    fn test_simulated_annealing() {
+        check_metaheuristic(|jobs, seed| simulated_annealing(jobs, config(seed)), true);
+    }
+
+    #[test]
+    fn test_simulated_annealing_history() {
+        for jobs in nonempty_test_instances() {
+            let result = simulated_annealing_with_history(jobs, config(0));
+            assert_eq!(result.history.len(), 2000);
+            assert!(result.history.iter().all(|&c| c >= result.schedule.c_max()));
+        }
+    }
+
+    #[test]
+    fn test_simulated_annealing_no_iterations() {
+        let mut config = config(0);
+        config.iterations = 0;
+        for jobs in nonempty_test_instances() {
+            let result = simulated_annealing_with_history(jobs.clone(), config);
+            assert_eq!(result.schedule, schrage(&JobList::new(jobs)).job_list);
+            assert!(result.history.is_empty());
+        }
+    }
+}
@@ -0,0 +1,366 @@
+use crate::rng::Rng;
+use crate::schrage::jobs::{Job, JobList};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Schedule of jobs on identical parallel machines.
+///
+/// Every machine runs its own [`JobList`] in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiMachineSchedule {
+    pub machines: Vec<JobList>,
+}
+
+impl MultiMachineSchedule {
+    /// Creates a new [`MultiMachineSchedule`].
+    pub fn new(machines: Vec<JobList>) -> MultiMachineSchedule {
+        MultiMachineSchedule { machines }
+    }
+
+    /// Returns the c max of this [`MultiMachineSchedule`],
+    /// the largest c max over all machines.
+    ///
+    /// Machines without jobs are skipped, returns 0 if there are no jobs.
+    pub fn c_max(&self) -> u32 {
+        self.machines
+            .iter()
+            .filter(|machine| !machine.jobs.is_empty())
+            .map(|machine| machine.c_max())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of jobs assigned to all machines.
+    pub fn job_count(&self) -> usize {
+        self.machines.iter().map(|machine| machine.jobs.len()).sum()
+    }
+}
+
+/// List scheduling on `m` identical parallel machines.
+///
+/// Jobs are taken in the given order and each one is assigned to the machine
+/// that becomes free first.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::parallel::list_scheduling;
+/// let jobs = vec![Job::new(0, 3, 0), Job::new(0, 2, 0), Job::new(0, 2, 0)];
+/// let result = list_scheduling(jobs, 2);
+/// assert_eq!(result.machines[0].jobs, vec![Job::new(0, 3, 0)]);
+/// assert_eq!(result.c_max(), 4);
+/// ```
+pub fn list_scheduling(jobs: Vec<Job>, m: usize) -> MultiMachineSchedule {
+    assert!(m > 0, "at least one machine is required");
+    let mut machines = vec![JobList::new(Vec::new()); m];
+    // Time at which every machine becomes free
+    let mut free_at = vec![0; m];
+
+    for job in jobs {
+        let machine = (0..m).min_by_key(|&k| free_at[k]).unwrap();
+        free_at[machine] = cmp::max(free_at[machine], job.delivery_time) + job.processing_time;
+        machines[machine].jobs.push(job);
+    }
+    MultiMachineSchedule::new(machines)
+}
+
+/// List scheduling on `n_machines` parallel machines where every job can
+/// only run on some of them.
+///
+/// Jobs are taken in the given order and each one is assigned to the
+/// machine that becomes free first out of the machines listed with it.
+///
+/// # Panics
+///
+/// Panics if `n_machines` is 0, if a job has no eligible machine or if a
+/// machine index is not below `n_machines`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::parallel::eligible_machine_list_scheduling;
+/// let jobs = vec![(Job::new(0, 3, 0), vec![0]), (Job::new(0, 2, 0), vec![0])];
+/// let result = eligible_machine_list_scheduling(jobs, 2);
+/// assert_eq!(result.machines[0].jobs.len(), 2);
+/// assert!(result.machines[1].jobs.is_empty());
+/// assert_eq!(result.c_max(), 5);
+/// ```
+pub fn eligible_machine_list_scheduling(
+    jobs: Vec<(Job, Vec<usize>)>,
+    n_machines: usize,
+) -> MultiMachineSchedule {
+    assert!(n_machines > 0, "at least one machine is required");
+    let mut machines = vec![JobList::new(Vec::new()); n_machines];
+    // Time at which every machine becomes free
+    let mut free_at = vec![0; n_machines];
+
+    for (job, eligible) in jobs {
+        assert!(
+            eligible.iter().all(|&k| k < n_machines),
+            "eligible machine out of range"
+        );
+        let machine = eligible
+            .into_iter()
+            .min_by_key(|&k| (free_at[k], k))
+            .expect("every job needs an eligible machine");
+        free_at[machine] = cmp::max(free_at[machine], job.delivery_time) + job.processing_time;
+        machines[machine].jobs.push(job);
+    }
+    MultiMachineSchedule::new(machines)
+}
+
+/// Longest processing time first (LPT) list scheduling
+/// on `m` identical parallel machines.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+pub fn lpt(jobs: Vec<Job>, m: usize) -> MultiMachineSchedule {
+    let mut by_processing_time = JobList::new(jobs).sorted_by_processing_time();
+    by_processing_time.reverse();
+    list_scheduling(by_processing_time, m)
+}
+
+/// List scheduling in a random order on `m` identical parallel machines.
+///
+/// Useful as a baseline when comparing other list scheduling orders.
+/// The same `seed` always gives the same schedule.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+pub fn list_scheduling_random(jobs: Vec<Job>, m: usize, seed: u64) -> MultiMachineSchedule {
+    let mut jobs = jobs;
+    Rng::new(seed).shuffle(&mut jobs);
+    list_scheduling(jobs, m)
+}
+
+/// Trivial lower bound on the optimal c max on `m` identical parallel machines.
+///
+/// It is the larger of the longest single job (`r + p + q`)
+/// and the total processing time spread evenly over all machines.
+/// Returns 0 if there are no jobs.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+pub fn trivial_lower_bound(jobs: &[Job], m: usize) -> u32 {
+    assert!(m > 0, "at least one machine is required");
+    let longest_job = jobs.iter().map(|j| j.total_time()).max().unwrap_or(0);
+    let total_processing_time: u32 = jobs.iter().map(|j| j.processing_time).sum();
+    cmp::max(longest_job, total_processing_time.div_ceil(m as u32))
+}
+
+/// Graham's bound on the c max of any list schedule on `m` identical
+/// parallel machines, extended with the delivery and cooldown times:
+/// `max(r) + sum(p) / m + (1 - 1 / m) * max(p) + max(q)`.
+///
+/// Returns 0 if there are no jobs.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+pub fn list_scheduling_upper_bound(jobs: &[Job], m: usize) -> u32 {
+    assert!(m > 0, "at least one machine is required");
+    let m = m as u64;
+    let max_delivery_time = jobs.iter().map(|j| j.delivery_time).max().unwrap_or(0);
+    let max_processing_time = jobs.iter().map(|j| j.processing_time).max().unwrap_or(0);
+    let max_cooldown_time = jobs.iter().map(|j| j.cooldown_time).max().unwrap_or(0);
+    let total_processing_time: u64 = jobs.iter().map(|j| j.processing_time as u64).sum();
+    let spread = (total_processing_time + (m - 1) * max_processing_time as u64) / m;
+    max_delivery_time + spread as u32 + max_cooldown_time
+}
+
+/// Upper bound on the optimal c max on `m` identical parallel machines.
+///
+/// Returns the c max of the [`lpt`] schedule, no formula bound can be
+/// tighter than it:
+///
+/// - The LPT guarantee `C_max <= (4/3 - 1/(3m)) * OPT` cannot be applied to
+///   [`trivial_lower_bound`], because the optimum may lie above it (e.g.
+///   three jobs of length 5 on two machines give a lower bound of 8 and LPT
+///   c max of 10 > 7/6 * 8).
+/// - [`list_scheduling_upper_bound`] bounds every list schedule, LPT
+///   included, so it is never below the LPT c max.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::parallel::lpt_approximation_upper_bound;
+/// let jobs = vec![Job::new(0, 5, 0), Job::new(0, 5, 0), Job::new(0, 5, 0)];
+/// assert_eq!(lpt_approximation_upper_bound(&jobs, 2), 10);
+/// ```
+pub fn lpt_approximation_upper_bound(jobs: &[Job], m: usize) -> u32 {
+    lpt(jobs.to_vec(), m).c_max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_jobs(n: usize, seed: u64) -> Vec<Job> {
+        let mut rng = Rng::new(seed);
+        (0..n)
+            .map(|_| Job::new(0, rng.gen_range(1, 100), 0))
+            .collect()
+    }
+
+    fn is_valid(schedule: &MultiMachineSchedule, jobs: &[Job], m: usize) -> bool {
+        let mut scheduled: Vec<Job> = schedule
+            .machines
+            .iter()
+            .flat_map(|machine| machine.jobs.clone())
+            .collect();
+        let mut expected = jobs.to_vec();
+        scheduled.sort_by_key(|j| (j.delivery_time, j.processing_time, j.cooldown_time));
+        expected.sort_by_key(|j| (j.delivery_time, j.processing_time, j.cooldown_time));
+        schedule.machines.len() == m && scheduled == expected
+    }
+
+    #[test]
+    fn test_lpt() {
+        let jobs = vec![
+            Job::new(0, 2, 0),
+            Job::new(0, 3, 0),
+            Job::new(0, 7, 0),
+            Job::new(0, 4, 0),
+            Job::new(0, 5, 0),
+        ];
+        let result = lpt(jobs.clone(), 2);
+        assert!(is_valid(&result, &jobs, 2));
+        assert_eq!(result.c_max(), 11);
+    }
+
+    #[test]
+    fn test_list_scheduling_respects_delivery_time() {
+        let jobs = vec![Job::new(10, 5, 3), Job::new(0, 2, 1)];
+        let result = list_scheduling(jobs, 1);
+        assert_eq!(result.c_max(), 18);
+    }
+
+    #[test]
+    fn test_list_scheduling_random_is_valid() {
+        let jobs = random_jobs(30, 1);
+        for seed in 0..20 {
+            let result = list_scheduling_random(jobs.clone(), 4, seed);
+            assert!(is_valid(&result, &jobs, 4));
+            assert_eq!(result.job_count(), jobs.len());
+        }
+        assert_eq!(
+            list_scheduling_random(jobs.clone(), 4, 5),
+            list_scheduling_random(jobs, 4, 5)
+        );
+    }
+
+    #[test]
+    fn test_list_scheduling_random_worse_than_lpt() {
+        let jobs = random_jobs(30, 2);
+        let lpt_c_max = lpt(jobs.clone(), 4).c_max() as f64;
+        let seeds = 50;
+        let mean: f64 = (0..seeds)
+            .map(|seed| list_scheduling_random(jobs.clone(), 4, seed).c_max() as f64)
+            .sum::<f64>()
+            / seeds as f64;
+        assert!(mean > lpt_c_max);
+    }
+
+    #[test]
+    fn test_eligible_machine_list_scheduling() {
+        let mut rng = Rng::new(10);
+        let m = 4;
+        // The cooldown time tells the jobs apart
+        let jobs: Vec<(Job, Vec<usize>)> = (0..30)
+            .map(|j| {
+                let eligible: Vec<usize> = (0..m).filter(|_| rng.gen_range(0, 1) == 1).collect();
+                let eligible = if eligible.is_empty() {
+                    vec![rng.gen_index(m)]
+                } else {
+                    eligible
+                };
+                (
+                    Job::new(rng.gen_range(0, 20), rng.gen_range(1, 10), j),
+                    eligible,
+                )
+            })
+            .collect();
+        let result = eligible_machine_list_scheduling(jobs.clone(), m);
+        let plain: Vec<Job> = jobs.iter().map(|(job, _)| *job).collect();
+        assert!(is_valid(&result, &plain, m));
+        for (k, machine) in result.machines.iter().enumerate() {
+            for job in &machine.jobs {
+                let (_, eligible) = &jobs[job.cooldown_time as usize];
+                assert!(eligible.contains(&k));
+            }
+        }
+
+        // Every machine eligible is plain list scheduling
+        let everywhere: Vec<(Job, Vec<usize>)> =
+            plain.iter().map(|&job| (job, (0..m).collect())).collect();
+        assert_eq!(
+            eligible_machine_list_scheduling(everywhere, m),
+            list_scheduling(plain, m)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_eligible_machine_list_scheduling_no_machine() {
+        eligible_machine_list_scheduling(vec![(Job::new(0, 1, 0), Vec::new())], 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_eligible_machine_list_scheduling_out_of_range() {
+        eligible_machine_list_scheduling(vec![(Job::new(0, 1, 0), vec![2])], 2);
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = list_scheduling_random(Vec::new(), 3, 0);
+        assert_eq!(result.machines.len(), 3);
+        assert_eq!(result.c_max(), 0);
+    }
+
+    #[test]
+    fn test_lpt_approximation_upper_bound() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let jobs: Vec<Job> = (0..15)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 50),
+                        rng.gen_range(1, 50),
+                        rng.gen_range(0, 50),
+                    )
+                })
+                .collect();
+            for m in 1..5 {
+                let lpt_c_max = lpt(jobs.clone(), m).c_max();
+                let bound = lpt_approximation_upper_bound(&jobs, m);
+                assert!(list_scheduling_upper_bound(&jobs, m) >= lpt_c_max);
+                assert_eq!(bound, lpt_c_max);
+                assert!(bound >= trivial_lower_bound(&jobs, m));
+            }
+        }
+    }
+
+    #[test]
+    fn test_trivial_lower_bound() {
+        let jobs = vec![Job::new(0, 5, 0), Job::new(0, 5, 0), Job::new(0, 5, 0)];
+        assert_eq!(trivial_lower_bound(&jobs, 2), 8);
+        assert_eq!(trivial_lower_bound(&jobs, 5), 5);
+        assert_eq!(trivial_lower_bound(&[], 2), 0);
+    }
+}
@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+/// Small seeded pseudo random number generator (SplitMix64).
+///
+/// It is not suitable for cryptography, but it is fast and the same seed
+/// always produces the same sequence, which makes randomized algorithms
+/// reproducible.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::rng::Rng;
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new [`Rng`] from a seed.
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random `f64` in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random number in `[low, high]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low > high`.
+    pub fn gen_range(&mut self, low: u32, high: u32) -> u32 {
+        assert!(low <= high, "empty range");
+        let span = (high - low) as u64 + 1;
+        low + (self.next_u64() % span) as u32
+    }
+
+    /// Returns a random index in `[0, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 0.
+    pub fn gen_index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "empty range");
+        (self.next_u64() % len as u64) as usize
+    }
+
+    /// Shuffles a slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_index(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// Returns a random permutation of `0..n`.
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut perm: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut perm);
+        perm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_different_seed_different_sequence() {
+        let a: Vec<u64> = {
+            let mut rng = Rng::new(1);
+            (0..10).map(|_| rng.next_u64()).collect()
+        };
+        let b: Vec<u64> = {
+            let mut rng = Rng::new(2);
+            (0..10).map(|_| rng.next_u64()).collect()
+        };
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = Rng::new(3);
+        for _ in 0..1000 {
+            let x = rng.gen_range(5, 10);
+            assert!((5..=10).contains(&x));
+            let f = rng.next_f64();
+            assert!((0.0..1.0).contains(&f));
+        }
+        assert_eq!(rng.gen_range(4, 4), 4);
+    }
+
+    #[test]
+    fn test_permutation() {
+        let mut rng = Rng::new(11);
+        let mut perm = rng.permutation(50);
+        perm.sort_unstable();
+        assert_eq!(perm, (0..50).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::schrage::jobs::{Job, JobList};
+use alloc::vec::Vec;
+
+/// Earliest due date first, minimizing the maximum lateness.
+///
+/// The jobs are run by ascending due date, jobs without one go last. If
+/// every job is delivered at time 0 this minimizes the maximum lateness
+/// (Jackson's rule). Otherwise it is only a heuristic, the delivery times
+/// are ignored when ordering and the machine waits for a job that is not
+/// delivered yet.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::lateness::{edd_lmax, maximum_lateness};
+/// let jobs = vec![Job::new(0, 4, 0).with_due_date(9), Job::new(0, 3, 0).with_due_date(3)];
+/// let result = edd_lmax(jobs);
+/// assert_eq!(result.jobs[0], Job::new(0, 3, 0).with_due_date(3));
+/// assert_eq!(maximum_lateness(&result), 0);
+/// ```
+pub fn edd_lmax(jobs: Vec<Job>) -> JobList {
+    let mut by_due_date = jobs;
+    by_due_date.sort_by_key(|job| (job.due_date.is_none(), job.due_date));
+    JobList::new(by_due_date)
+}
+
+/// Returns the largest [`Job::lateness`] of the jobs run in the given order.
+///
+/// # Panics
+///
+/// Panics if the schedule is empty.
+pub fn maximum_lateness(schedule: &JobList) -> i32 {
+    schedule.maximum_lateness(&schedule.completion_times())
+}
+
+/// Returns the largest [`Job::tardiness`] of the jobs run in the given
+/// order, 0 if the schedule is empty.
+pub fn maximum_tardiness(schedule: &JobList) -> u32 {
+    schedule
+        .completion_times()
+        .into_iter()
+        .zip(&schedule.jobs)
+        .map(|(c, job)| job.tardiness(c))
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::test_util::min_over_permutations;
+
+    fn min_by_brute_force(jobs: &[Job]) -> i32 {
+        min_over_permutations(jobs, |jobs: &[Job]| {
+            maximum_lateness(&JobList::new(jobs.to_vec()))
+        })
+    }
+
+    #[test]
+    fn test_edd_lmax_example() {
+        let jobs = vec![
+            Job::new(0, 4, 0).with_due_date(10), // 1
+            Job::new(0, 2, 0).with_due_date(6),  // 2
+            Job::new(0, 3, 0).with_due_date(4),  // 3
+            Job::new(0, 5, 0).with_due_date(12), // 4
+        ];
+        let natural = JobList::new(jobs.clone());
+        // Done at 4, 6, 9 and 14
+        assert_eq!(maximum_lateness(&natural), 5);
+        assert_eq!(maximum_tardiness(&natural), 5);
+
+        // 3, 2, 1, 4 done at 3, 5, 9 and 14
+        let result = edd_lmax(jobs.clone());
+        assert_eq!(result.jobs, vec![jobs[2], jobs[1], jobs[0], jobs[3]]);
+        assert_eq!(maximum_lateness(&result), 2);
+        assert_eq!(maximum_tardiness(&result), 2);
+        assert_eq!(min_by_brute_force(&jobs), 2);
+    }
+
+    #[test]
+    fn test_edd_lmax_optimal_without_delivery_times() {
+        let mut rng = Rng::new(37);
+        for n in 1..7 {
+            for _ in 0..5 {
+                let jobs: Vec<Job> = (0..n)
+                    .map(|_| {
+                        Job::new(0, rng.gen_range(1, 20), 0).with_due_date(rng.gen_range(0, 60))
+                    })
+                    .collect();
+                let result = edd_lmax(jobs.clone());
+                assert_eq!(result.jobs.len(), n);
+                assert_eq!(maximum_lateness(&result), min_by_brute_force(&jobs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_edd_lmax_without_due_dates_last() {
+        let jobs = vec![Job::new(0, 1, 0), Job::new(0, 2, 0).with_due_date(50)];
+        let result = edd_lmax(jobs);
+        assert_eq!(result.jobs[1], Job::new(0, 1, 0));
+        assert_eq!(maximum_tardiness(&JobList::new(Vec::new())), 0);
+    }
+}
@@ -0,0 +1,199 @@
+pub mod lateness;
+pub mod moore;
+pub mod srpt;
+pub mod wspt;
+
+use crate::schrage::jobs::{Job, JobList};
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Schedules the jobs one at a time, every time the machine is free picking
+/// the ready job with the smallest `key` (the first given on ties).
+/// If no job is ready the machine waits for the next delivery.
+fn dispatch<K: Ord>(jobs: Vec<Job>, key: impl Fn(&Job) -> K) -> JobList {
+    let mut pending = JobList::new(jobs).sorted_by_delivery_time();
+    let mut pi = JobList::new(Vec::new());
+    let mut t: u32 = 0;
+    while !pending.is_empty() {
+        // Jobs are sorted by delivery time, so the ready ones are a prefix
+        t = cmp::max(t, pending[0].delivery_time);
+        let ready = pending.partition_point(|job| job.delivery_time <= t);
+        let position = (0..ready).min_by_key(|&k| key(&pending[k])).unwrap();
+        let job = pending.remove(position);
+        t += job.processing_time;
+        pi.jobs.push(job);
+    }
+    pi
+}
+
+/// Shortest processing time first.
+///
+/// Every time the machine is free the ready job with the shortest
+/// processing time is run, no job starts before its delivery time.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::spt;
+/// let result = spt(vec![Job::new(0, 5, 1), Job::new(0, 2, 1), Job::new(1, 1, 1)]);
+/// assert_eq!(
+///     result.jobs,
+///     vec![Job::new(0, 2, 1), Job::new(1, 1, 1), Job::new(0, 5, 1)]
+/// );
+/// ```
+pub fn spt(jobs: Vec<Job>) -> JobList {
+    dispatch(jobs, |job| job.processing_time)
+}
+
+/// Longest processing time first.
+///
+/// Every time the machine is free the ready job with the longest
+/// processing time is run, no job starts before its delivery time.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::lpt;
+/// let result = lpt(vec![Job::new(0, 2, 1), Job::new(0, 5, 1), Job::new(1, 9, 1)]);
+/// assert_eq!(
+///     result.jobs,
+///     vec![Job::new(0, 5, 1), Job::new(1, 9, 1), Job::new(0, 2, 1)]
+/// );
+/// ```
+pub fn lpt(jobs: Vec<Job>) -> JobList {
+    dispatch(jobs, |job| cmp::Reverse(job.processing_time))
+}
+
+/// Earliest delivery time first, jobs delivered at the same time
+/// are ordered by their total time `r + p + q`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::edd;
+/// let result = edd(vec![Job::new(3, 2, 1), Job::new(0, 5, 9), Job::new(0, 5, 1)]);
+/// assert_eq!(
+///     result.jobs,
+///     vec![Job::new(0, 5, 1), Job::new(0, 5, 9), Job::new(3, 2, 1)]
+/// );
+/// ```
+pub fn edd(jobs: Vec<Job>) -> JobList {
+    let mut by_delivery_time = jobs;
+    by_delivery_time.sort_by_key(|job| (job.delivery_time, job.total_time()));
+    JobList::new(by_delivery_time)
+}
+
+/// First in, first out: the jobs are run in the given order,
+/// each one as soon as it is delivered and the machine is free.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::fifo;
+/// let jobs = vec![Job::new(3, 2, 1), Job::new(0, 5, 9)];
+/// assert_eq!(fifo(jobs.clone()).jobs, jobs);
+/// ```
+pub fn fifo(jobs: Vec<Job>) -> JobList {
+    JobList::new(jobs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schrage::schrage;
+
+    fn examples() -> Vec<(Vec<Job>, u32)> {
+        vec![
+            (
+                vec![
+                    Job::new(10, 5, 7),  // 1
+                    Job::new(13, 6, 26), // 2
+                    Job::new(11, 7, 24), // 3
+                    Job::new(20, 4, 21), // 4
+                    Job::new(30, 3, 8),  // 5
+                    Job::new(0, 6, 17),  // 6
+                    Job::new(30, 2, 0),  // 7
+                ],
+                53,
+            ),
+            (
+                vec![
+                    Job::new(1, 5, 9), // 1
+                    Job::new(4, 5, 4), // 2
+                    Job::new(1, 4, 6), // 3
+                    Job::new(7, 3, 3), // 4
+                    Job::new(3, 6, 8), // 5
+                    Job::new(4, 7, 1), // 6
+                ],
+                32,
+            ),
+            (
+                vec![
+                    Job::new(52, 1, 56),   // 1
+                    Job::new(70, 4, 93),   // 2
+                    Job::new(112, 22, 79), // 3
+                    Job::new(5, 14, 125),  // 4
+                    Job::new(8, 16, 114),  // 5
+                    Job::new(71, 7, 71),   // 6
+                    Job::new(90, 2, 13),   // 7
+                    Job::new(2, 20, 88),   // 8
+                    Job::new(52, 20, 56),  // 9
+                    Job::new(9, 28, 94),   // 10
+                ],
+                213,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_rules_bound_schrage() {
+        for (jobs, schrage_c_max) in examples() {
+            assert_eq!(schrage(&JobList::new(jobs.clone())).c_max(), schrage_c_max);
+            assert!(spt(jobs.clone()).c_max() >= schrage_c_max);
+            assert!(lpt(jobs.clone()).c_max() >= schrage_c_max);
+            assert!(edd(jobs.clone()).c_max() >= schrage_c_max);
+            assert!(fifo(jobs).c_max() >= schrage_c_max);
+        }
+    }
+
+    #[test]
+    fn test_rules_keep_jobs() {
+        for (jobs, _) in examples() {
+            let key = |j: &Job| (j.delivery_time, j.processing_time, j.cooldown_time);
+            let mut expected = jobs.clone();
+            expected.sort_by_key(key);
+            for rule in [spt, lpt, edd, fifo] {
+                let mut scheduled = rule(jobs.clone()).jobs;
+                scheduled.sort_by_key(key);
+                assert_eq!(scheduled, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spt_waits_for_delivery() {
+        // The shortest job is not delivered yet when the machine
+        // is free at 3, so the longer one runs first
+        let result = spt(vec![
+            Job::new(0, 5, 0),
+            Job::new(4, 1, 0),
+            Job::new(0, 3, 0),
+        ]);
+        assert_eq!(
+            result.jobs,
+            vec![Job::new(0, 3, 0), Job::new(0, 5, 0), Job::new(4, 1, 0)]
+        );
+        assert_eq!(result.c_max(), 9);
+    }
+
+    #[test]
+    fn test_rules_empty() {
+        for rule in [spt, lpt, edd, fifo] {
+            assert!(rule(Vec::new()).jobs.is_empty());
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::rules::lateness::edd_lmax;
+use crate::schrage::jobs::{Job, JobList};
+use alloc::vec::Vec;
+
+/// Moore-Hodgson algorithm, minimizing the number of late jobs.
+///
+/// The jobs are taken in earliest due date order and added to the on-time
+/// jobs, whenever the last one added would be late the longest on-time job
+/// is removed. The on-time jobs are run first in earliest due date order,
+/// then the removed ones. Jobs without a due date are never late. If every
+/// job is delivered at time 0 no schedule has fewer late jobs, otherwise
+/// it is only a heuristic.
+///
+/// Returns the schedule and its number of jobs on time, the complement of
+/// [`late_job_count`].
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::moore::moore_hodgson;
+/// let jobs = vec![Job::new(0, 5, 0).with_due_date(5), Job::new(0, 2, 0).with_due_date(6)];
+/// let (result, on_time) = moore_hodgson(jobs);
+/// assert_eq!(on_time, 1);
+/// assert_eq!(result.jobs[0], Job::new(0, 2, 0).with_due_date(6));
+/// ```
+pub fn moore_hodgson(jobs: Vec<Job>) -> (JobList, usize) {
+    let n = jobs.len();
+    let mut on_time = JobList::new(Vec::new());
+    let mut late: Vec<Job> = Vec::new();
+    for job in edd_lmax(jobs) {
+        on_time.jobs.push(job);
+        let completion_time = *on_time.completion_times().last().unwrap();
+        if job.tardiness(completion_time) > 0 {
+            let longest = (0..on_time.jobs.len())
+                .max_by_key(|&k| on_time.jobs[k].processing_time)
+                .unwrap();
+            late.push(on_time.jobs.remove(longest));
+        }
+    }
+    let mut result = on_time;
+    result.extend(late);
+    let late_jobs = late_job_count(&result);
+    (result, n - late_jobs)
+}
+
+/// Returns the number of jobs completed after their due date when run
+/// in the given order.
+pub fn late_job_count(schedule: &JobList) -> usize {
+    schedule
+        .completion_times()
+        .into_iter()
+        .zip(&schedule.jobs)
+        .filter(|&(c, job)| job.tardiness(c) > 0)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::test_util::min_over_permutations;
+
+    fn min_by_brute_force(jobs: &[Job]) -> usize {
+        min_over_permutations(jobs, |jobs: &[Job]| {
+            late_job_count(&JobList::new(jobs.to_vec()))
+        })
+    }
+
+    #[test]
+    fn test_moore_hodgson_removes_long_job() {
+        let long = Job::new(0, 10, 0).with_due_date(10);
+        let short = vec![
+            Job::new(0, 2, 0).with_due_date(11),
+            Job::new(0, 2, 0).with_due_date(12),
+            Job::new(0, 2, 0).with_due_date(13),
+        ];
+        let mut jobs = vec![long];
+        jobs.extend(short.clone());
+        // In earliest due date order only the long job is on time
+        assert_eq!(late_job_count(&edd_lmax(jobs.clone())), 3);
+
+        let (result, on_time) = moore_hodgson(jobs);
+        assert_eq!(on_time, 3);
+        assert_eq!(result.jobs[..3], short[..]);
+        assert_eq!(result.jobs[3], long);
+        assert_eq!(late_job_count(&result), 1);
+    }
+
+    #[test]
+    fn test_moore_hodgson_optimal_without_delivery_times() {
+        let mut rng = Rng::new(43);
+        for n in 1..7 {
+            for _ in 0..5 {
+                let jobs: Vec<Job> = (0..n)
+                    .map(|_| {
+                        Job::new(0, rng.gen_range(1, 20), 0).with_due_date(rng.gen_range(0, 50))
+                    })
+                    .collect();
+                let (result, on_time) = moore_hodgson(jobs.clone());
+                assert_eq!(result.jobs.len(), n);
+                assert_eq!(on_time, n - late_job_count(&result));
+                assert_eq!(n - on_time, min_by_brute_force(&jobs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_moore_hodgson_empty() {
+        let (result, on_time) = moore_hodgson(Vec::new());
+        assert!(result.jobs.is_empty());
+        assert_eq!(on_time, 0);
+    }
+}
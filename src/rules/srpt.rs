@@ -0,0 +1,142 @@
+use crate::schrage::jobs::{Job, JobSchedule};
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Shortest remaining processing time first, preemptive.
+///
+/// At every moment the delivered job with the least processing time left
+/// runs, a newly delivered job preempts the running one if it is shorter
+/// than what is left of it. This minimizes the total completion time of a
+/// preemptive schedule with delivery times.
+///
+/// Returns the schedule in the same form as
+/// [`schrage_preemptive`](crate::schrage::schrage_preemptive).
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::srpt::{srpt, total_completion_time};
+/// let schedule = srpt(vec![Job::new(0, 5, 1), Job::new(1, 2, 1)]);
+/// assert_eq!(schedule.timetable, vec![(0, 0, 1), (1, 1, 2), (3, 0, 4)]);
+/// assert_eq!(total_completion_time(&schedule), 3 + 7);
+/// ```
+pub fn srpt(jobs: Vec<Job>) -> JobSchedule {
+    let mut shortest_delivery_jobs: Vec<usize> = (0..jobs.len()).collect();
+    shortest_delivery_jobs.sort_by_key(|&i| jobs[i].delivery_time);
+    let mut next = 0;
+    let mut ready_to_run: Vec<usize> = Vec::new();
+    // Processing time left for every job
+    let mut remaining: Vec<u32> = jobs.iter().map(|j| j.processing_time).collect();
+    let mut t: u32 = 0;
+    let mut timetable: Vec<(u32, usize, u32)> = Vec::new();
+
+    while next < shortest_delivery_jobs.len() || !ready_to_run.is_empty() {
+        while next < shortest_delivery_jobs.len()
+            && jobs[shortest_delivery_jobs[next]].delivery_time <= t
+        {
+            ready_to_run.push(shortest_delivery_jobs[next]);
+            next += 1;
+        }
+        if ready_to_run.is_empty() {
+            t = jobs[shortest_delivery_jobs[next]].delivery_time;
+            continue;
+        }
+
+        // Keep the running job on ties, so that it is not preempted needlessly
+        let running = timetable.last().map(|&(_, j, _)| j);
+        let position = (0..ready_to_run.len())
+            .min_by_key(|&k| {
+                let i = ready_to_run[k];
+                (remaining[i], running != Some(i), k)
+            })
+            .unwrap();
+        let i = ready_to_run[position];
+
+        // Run the job until it is done or until the next job arrives
+        let mut duration = remaining[i];
+        if next < shortest_delivery_jobs.len() {
+            let next_delivery = jobs[shortest_delivery_jobs[next]].delivery_time;
+            duration = cmp::min(duration, next_delivery - t);
+        }
+        match timetable.last_mut() {
+            Some(last) if last.1 == i && last.0 + last.2 == t => last.2 += duration,
+            _ => timetable.push((t, i, duration)),
+        }
+        remaining[i] -= duration;
+        t += duration;
+        if remaining[i] == 0 {
+            ready_to_run.remove(position);
+        }
+    }
+    JobSchedule::new(jobs, timetable)
+}
+
+/// Returns the sum of the completion times of all the jobs of a schedule,
+/// the time the last segment of each job ends.
+pub fn total_completion_time(schedule: &JobSchedule) -> u32 {
+    (0..schedule.jobs.len())
+        .map(|i| schedule.completion_time(i))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::schrage::jobs::JobList;
+    use crate::test_util::min_over_permutations;
+
+    fn min_by_brute_force(jobs: &[Job]) -> u32 {
+        min_over_permutations(jobs, |jobs: &[Job]| {
+            JobList::new(jobs.to_vec()).completion_times().iter().sum()
+        })
+    }
+
+    #[test]
+    fn test_srpt_beats_fifo() {
+        let jobs = vec![Job::new(0, 10, 3), Job::new(1, 2, 8), Job::new(2, 1, 5)];
+        // FIFO is done at 10, 12 and 13
+        let fifo: u32 = JobList::new(jobs.clone()).completion_times().iter().sum();
+        assert_eq!(fifo, 35);
+
+        let schedule = srpt(jobs);
+        assert_eq!(
+            schedule.timetable,
+            vec![(0, 0, 1), (1, 1, 2), (3, 2, 1), (4, 0, 9)]
+        );
+        // Done at 13, 3 and 4
+        assert_eq!(total_completion_time(&schedule), 20);
+        assert!(total_completion_time(&schedule) < fifo);
+        assert_eq!(schedule.c_max(), 16);
+        assert!(schedule.is_feasible());
+    }
+
+    #[test]
+    fn test_srpt_bounds_every_sequence() {
+        let mut rng = Rng::new(47);
+        for n in 1..7 {
+            for _ in 0..5 {
+                let jobs: Vec<Job> = (0..n)
+                    .map(|_| {
+                        Job::new(
+                            rng.gen_range(0, 30),
+                            rng.gen_range(1, 10),
+                            rng.gen_range(0, 10),
+                        )
+                    })
+                    .collect();
+                let schedule = srpt(jobs.clone());
+                assert!(schedule.is_feasible());
+                assert!(total_completion_time(&schedule) <= min_by_brute_force(&jobs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_srpt_empty() {
+        let schedule = srpt(Vec::new());
+        assert!(schedule.timetable.is_empty());
+        assert_eq!(total_completion_time(&schedule), 0);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::schrage::jobs::{Job, JobList};
+use alloc::vec::Vec;
+
+/// Weighted shortest processing time first (Smith's rule).
+///
+/// The jobs are run by ascending `processing_time / weight`, compared by
+/// cross multiplication so no floating point is involved, jobs with a weight
+/// of 0 go last. If every job is delivered at time 0 this minimizes the
+/// total weighted completion time. Otherwise the problem is NP-hard and this
+/// is only a heuristic, the delivery times are ignored when ordering and the
+/// machine waits for a job that is not delivered yet.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::rules::wspt::{total_weighted_completion_time, wspt};
+/// let result = wspt(vec![Job::new(0, 4, 0), Job::new(0, 3, 0).with_weight(2)]);
+/// assert_eq!(result.jobs, vec![Job::new(0, 3, 0).with_weight(2), Job::new(0, 4, 0)]);
+/// assert_eq!(total_weighted_completion_time(&result), 2 * 3 + 7);
+/// ```
+pub fn wspt(jobs: Vec<Job>) -> JobList {
+    let mut by_ratio = jobs;
+    by_ratio.sort_by(|a, b| {
+        let a_ratio = a.processing_time as u64 * b.weight as u64;
+        let b_ratio = b.processing_time as u64 * a.weight as u64;
+        // A job without weight never comes before one with
+        (a.weight == 0)
+            .cmp(&(b.weight == 0))
+            .then(a_ratio.cmp(&b_ratio))
+    });
+    JobList::new(by_ratio)
+}
+
+/// Returns the total weighted completion time `sum(w_j * C_j)` of the jobs
+/// run in the given order, where `C_j` is the time job `j` is done on the
+/// machine (without its cooldown time).
+pub fn total_weighted_completion_time(schedule: &JobList) -> u64 {
+    schedule
+        .completion_times()
+        .into_iter()
+        .zip(&schedule.jobs)
+        .map(|(c, job)| job.weight as u64 * c as u64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::test_util::min_over_permutations;
+
+    fn min_by_brute_force(jobs: &[Job]) -> u64 {
+        min_over_permutations(jobs, |jobs: &[Job]| {
+            total_weighted_completion_time(&JobList::new(jobs.to_vec()))
+        })
+    }
+
+    #[test]
+    fn test_wspt_optimal_without_delivery_times() {
+        let mut rng = Rng::new(31);
+        for n in 1..7 {
+            for _ in 0..5 {
+                let jobs: Vec<Job> = (0..n)
+                    .map(|_| {
+                        Job::new(0, rng.gen_range(1, 20), rng.gen_range(0, 20))
+                            .with_weight(rng.gen_range(1, 5))
+                    })
+                    .collect();
+                let result = wspt(jobs.clone());
+                assert_eq!(result.jobs.len(), n);
+                assert_eq!(
+                    total_weighted_completion_time(&result),
+                    min_by_brute_force(&jobs)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wspt_unit_weights_is_spt() {
+        let jobs = vec![Job::new(0, 5, 1), Job::new(0, 2, 9), Job::new(0, 3, 4)];
+        assert_eq!(
+            wspt(jobs).jobs,
+            vec![Job::new(0, 2, 9), Job::new(0, 3, 4), Job::new(0, 5, 1)]
+        );
+    }
+
+    #[test]
+    fn test_wspt_zero_weight_last() {
+        let jobs = vec![Job::new(0, 1, 0).with_weight(0), Job::new(0, 50, 0)];
+        let result = wspt(jobs);
+        assert_eq!(result.jobs[0], Job::new(0, 50, 0));
+        assert_eq!(total_weighted_completion_time(&result), 50);
+        assert_eq!(total_weighted_completion_time(&JobList::new(Vec::new())), 0);
+    }
+}
@@ -0,0 +1,1626 @@
+use crate::rng::Rng;
+use crate::schrage::jobs::{Job, JobList, JobSchedule};
+pub use crate::schrage::ProfilingData;
+use crate::schrage::{schrage, schrage_order_profiled, schrage_preemptive};
+use std::cmp;
+use std::ops::Range;
+
+/// Quality metrics of the Schrage algorithm on a single instance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchrageDiagnostics {
+    /// Number of jobs.
+    pub n: usize,
+    /// C max of the Schrage schedule.
+    pub schrage_c_max: u32,
+    /// C max of the preemptive Schrage schedule, a lower bound on the optimum.
+    pub preemptive_lb: u32,
+    /// Largest `r + p + q` of a single job, a weaker lower bound.
+    pub trivial_lb: u32,
+    /// Gap between `schrage_c_max` and `preemptive_lb` in percent.
+    pub gap_percent: f64,
+    /// Whether the Schrage schedule is proven optimal,
+    /// i.e. its c max reaches `preemptive_lb`.
+    pub is_schrage_optimal: bool,
+}
+
+/// Schrage algorithm that also counts the work it does.
+///
+/// Returns the same schedule as [`schrage`].
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::schrage_with_runtime_profiling;
+/// let jobs = vec![Job::new(0, 6, 17), Job::new(10, 5, 7)];
+/// let (result, profile) = schrage_with_runtime_profiling(jobs);
+/// assert_eq!(result.c_max(), 23);
+/// assert_eq!(profile.jobs_processed, 2);
+/// assert_eq!(profile.queue_operations, 4);
+/// // A single job is ready every time
+/// assert_eq!(profile.ready_jobs_scanned, 2);
+/// assert_eq!(profile.idle_skips, 1);
+/// ```
+pub fn schrage_with_runtime_profiling(jobs: Vec<Job>) -> (JobList, ProfilingData) {
+    let jobs = JobList::new(jobs);
+    let mut profile = ProfilingData::default();
+    let order = schrage_order_profiled(&jobs, &mut profile);
+    let result = JobList::new(order.into_iter().map(|i| jobs.jobs[i]).collect());
+    (result, profile)
+}
+
+/// Returns a copy of the jobs with the processing time of one job replaced.
+///
+/// # Panics
+///
+/// Panics if `job_index` is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::modify_job_processing_time;
+/// let jobs = vec![Job::new(0, 6, 17), Job::new(10, 5, 7)];
+/// let result = modify_job_processing_time(&jobs, 1, 9);
+/// assert_eq!(result, vec![Job::new(0, 6, 17), Job::new(10, 9, 7)]);
+/// ```
+pub fn modify_job_processing_time(jobs: &[Job], job_index: usize, new_p: u32) -> Vec<Job> {
+    let mut modified = jobs.to_vec();
+    modified[job_index].processing_time = new_p;
+    modified
+}
+
+/// Returns the c max of the jobs run in the given order
+/// for every processing time of one job in `p_range`.
+///
+/// # Panics
+///
+/// Panics if `job_index` is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::c_max_as_function_of_p;
+/// let jobs = vec![Job::new(0, 6, 17), Job::new(10, 5, 7)];
+/// assert_eq!(c_max_as_function_of_p(&jobs, 0, 5..8), vec![22, 23, 24]);
+/// ```
+pub fn c_max_as_function_of_p(jobs: &[Job], job_index: usize, p_range: Range<u32>) -> Vec<u32> {
+    assert!(job_index < jobs.len(), "job index out of bounds");
+    p_range
+        .map(|p| JobList::new(modify_job_processing_time(jobs, job_index, p)).c_max())
+        .collect()
+}
+
+/// Returns a fast, order dependent hash of the jobs of a schedule, all of
+/// their fields included.
+///
+/// Equal schedules always have equal fingerprints, so two schedules
+/// can be compared (or stored in a set) by their fingerprints alone.
+/// Uses the multiply-rotate scheme of `FxHasher`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::schedule_fingerprint;
+/// let a = JobList::new(vec![Job::new(0, 6, 17), Job::new(10, 5, 7)]);
+/// let b = JobList::new(vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]);
+/// assert_eq!(schedule_fingerprint(&a), schedule_fingerprint(&a.clone()));
+/// assert_ne!(schedule_fingerprint(&a), schedule_fingerprint(&b));
+/// ```
+pub fn schedule_fingerprint(schedule: &JobList) -> u64 {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+    let mut hash: u64 = 0;
+    let mut add = |word: u64| hash = (hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    add(schedule.jobs.len() as u64);
+    for job in &schedule.jobs {
+        add(job.delivery_time as u64);
+        add(job.processing_time as u64);
+        add(job.cooldown_time as u64);
+        // Above u32::MAX if there is a due date, distinct from no due date
+        add(job.due_date.map_or(0, |d| (1 << 32) | d as u64));
+        add(job.weight as u64);
+    }
+    hash
+}
+
+/// Compares the Schrage schedule against the lower bounds of an instance.
+///
+/// Every metric is 0 for an empty instance, which Schrage trivially solves
+/// optimally.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::diagnose;
+/// let result = diagnose(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]);
+/// assert_eq!(result.schrage_c_max, 15);
+/// assert!(result.is_schrage_optimal);
+/// ```
+pub fn diagnose(jobs: Vec<Job>) -> SchrageDiagnostics {
+    if jobs.is_empty() {
+        return SchrageDiagnostics {
+            n: 0,
+            schrage_c_max: 0,
+            preemptive_lb: 0,
+            trivial_lb: 0,
+            gap_percent: 0.0,
+            is_schrage_optimal: true,
+        };
+    }
+    let jobs = JobList::new(jobs);
+    let schrage_c_max = schrage(&jobs).c_max();
+    let preemptive_lb = schrage_preemptive(&jobs).c_max();
+    let trivial_lb = jobs.c_max_lower_bound();
+    let gap_percent = if preemptive_lb == 0 {
+        0.0
+    } else {
+        (schrage_c_max - preemptive_lb) as f64 / preemptive_lb as f64 * 100.0
+    };
+    SchrageDiagnostics {
+        n: jobs.jobs.len(),
+        schrage_c_max,
+        preemptive_lb,
+        trivial_lb,
+        gap_percent,
+        is_schrage_optimal: schrage_c_max == preemptive_lb,
+    }
+}
+
+/// Returns, for every job of the schedule, by how much its delivery time can
+/// grow before the c max of the schedule (run in the given order) grows,
+/// the "delivery time slack" of the job.
+///
+/// A job on the critical path that starts right at its delivery time
+/// has no slack.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::delivery_time_sensitivity;
+/// let js = JobList::new(vec![Job::new(0, 5, 20), Job::new(2, 5, 1)]);
+/// assert_eq!(delivery_time_sensitivity(&js), vec![0, 17]);
+/// ```
+pub fn delivery_time_sensitivity(schedule: &JobList) -> Vec<u32> {
+    let jobs = &schedule.jobs;
+    if jobs.is_empty() {
+        return Vec::new();
+    }
+    let c_max = schedule.c_max();
+
+    // Start and end time of every job
+    let mut starts = vec![0; jobs.len()];
+    let mut ends = vec![0; jobs.len()];
+    let mut t = 0;
+    for (i, job) in jobs.iter().enumerate() {
+        starts[i] = cmp::max(t, job.delivery_time);
+        t = starts[i] + job.processing_time;
+        ends[i] = t;
+    }
+
+    (0..jobs.len())
+        .map(|j| {
+            // A delay of job j shifts every following job by what is left
+            // of it after the idle gaps in between, and each of them can
+            // be shifted only until it reaches c max.
+            let mut gaps = 0;
+            let mut allowed_shift = u32::MAX;
+            for k in j..jobs.len() {
+                if k > j {
+                    gaps += starts[k] - ends[k - 1];
+                }
+                let slack = c_max - ends[k] - jobs[k].cooldown_time;
+                allowed_shift = cmp::min(allowed_shift, slack + gaps);
+            }
+            starts[j] - jobs[j].delivery_time + allowed_shift
+        })
+        .collect()
+}
+
+/// Returns the c max of `n_samples` random permutations of the jobs.
+///
+/// # Panics
+///
+/// Panics if empty job list and `n_samples > 0`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::sample_random_c_max;
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(0, 5, 10)];
+/// let samples = sample_random_c_max(&jobs, 10, 0);
+/// assert_eq!(samples.len(), 10);
+/// assert!(samples.iter().all(|&c| c == 15 || c == 20));
+/// ```
+pub fn sample_random_c_max(jobs: &[Job], n_samples: usize, seed: u64) -> Vec<u32> {
+    let mut rng = Rng::new(seed);
+    let mut permutation = JobList::new(jobs.to_vec());
+    (0..n_samples)
+        .map(|_| {
+            rng.shuffle(&mut permutation.jobs);
+            permutation.c_max()
+        })
+        .collect()
+}
+
+/// Returns the mean of c max samples.
+///
+/// # Panics
+///
+/// Panics if there are no samples.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::analysis::c_max_mean;
+/// assert_eq!(c_max_mean(&[15, 20, 25]), 20.0);
+/// ```
+pub fn c_max_mean(samples: &[u32]) -> f64 {
+    assert!(!samples.is_empty(), "no samples");
+    samples.iter().map(|&c| c as f64).sum::<f64>() / samples.len() as f64
+}
+
+/// Returns the (population) standard deviation of c max samples.
+///
+/// # Panics
+///
+/// Panics if there are no samples.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::analysis::c_max_std;
+/// assert_eq!(c_max_std(&[15, 25]), 5.0);
+/// assert_eq!(c_max_std(&[20, 20]), 0.0);
+/// ```
+pub fn c_max_std(samples: &[u32]) -> f64 {
+    let mean = c_max_mean(samples);
+    let variance = samples
+        .iter()
+        .map(|&c| (c as f64 - mean).powi(2))
+        .sum::<f64>()
+        / samples.len() as f64;
+    variance.sqrt()
+}
+
+/// Returns the c max of `n_samples` runs of the jobs in the given order with
+/// stochastic processing times, the processing time of job `i` being drawn
+/// uniformly from `p_distributions[i] = (p_min, p_max)`, both inclusive.
+/// The other times of the jobs are kept.
+///
+/// # Panics
+///
+/// Panics if there is not one distribution per job, if a `p_min` is greater
+/// than its `p_max` or if empty job list and `n_samples > 0`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::compute_expected_c_max_distribution;
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(0, 5, 10)];
+/// let samples = compute_expected_c_max_distribution(jobs, vec![(5, 5), (2, 8)], 10, 0);
+/// assert_eq!(samples.len(), 10);
+/// assert!(samples.iter().all(|&c| (17..=23).contains(&c)));
+/// ```
+pub fn compute_expected_c_max_distribution(
+    jobs: Vec<Job>,
+    p_distributions: Vec<(u32, u32)>,
+    n_samples: usize,
+    seed: u64,
+) -> Vec<u32> {
+    assert_eq!(
+        jobs.len(),
+        p_distributions.len(),
+        "one distribution per job is needed"
+    );
+    assert!(
+        p_distributions.iter().all(|&(p_min, p_max)| p_min <= p_max),
+        "p_min greater than p_max"
+    );
+    let mut rng = Rng::new(seed);
+    let mut sampled = JobList::new(jobs);
+    (0..n_samples)
+        .map(|_| {
+            for (job, &(p_min, p_max)) in sampled.jobs.iter_mut().zip(&p_distributions) {
+                job.processing_time = rng.gen_range(p_min, p_max);
+            }
+            sampled.c_max()
+        })
+        .collect()
+}
+
+/// Returns the `(mean, std, min, max)` of c max samples, see [`c_max_mean`]
+/// and [`c_max_std`].
+///
+/// # Panics
+///
+/// Panics if there are no samples.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::analysis::c_max_distribution_statistics;
+/// assert_eq!(c_max_distribution_statistics(&[25, 15]), (20.0, 5.0, 15, 25));
+/// ```
+pub fn c_max_distribution_statistics(samples: &[u32]) -> (f64, f64, u32, u32) {
+    let mean = c_max_mean(samples);
+    let std = c_max_std(samples);
+    let min = *samples.iter().min().unwrap();
+    let max = *samples.iter().max().unwrap();
+    (mean, std, min, max)
+}
+
+/// Returns the conflict graph of a schedule as adjacency lists.
+///
+/// Every job of `jobs` is a node, two jobs are connected if their execution
+/// intervals, from the start of their first segment to the end of their
+/// last one, overlap. On a single machine this only happens when a job is
+/// preempted and another one runs in between, so a non-preemptive schedule
+/// has no edges. Every adjacency list is sorted.
+///
+/// # Panics
+///
+/// Panics if the timetable refers to a job out of bounds of `jobs`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobSchedule};
+/// use proc_opt::schrage::analysis::build_conflict_graph;
+/// let jobs = vec![Job::new(0, 4, 1), Job::new(2, 2, 10)];
+/// let schedule = JobSchedule::new(jobs.clone(), vec![(0, 0, 2), (2, 1, 2), (4, 0, 2)]);
+/// assert_eq!(build_conflict_graph(&jobs, &schedule), vec![vec![1], vec![0]]);
+/// ```
+pub fn build_conflict_graph(jobs: &[Job], schedule: &JobSchedule) -> Vec<Vec<usize>> {
+    // Execution interval of every job, None for jobs not in the timetable
+    let mut intervals: Vec<Option<(u32, u32)>> = vec![None; jobs.len()];
+    for &(start, job_index, duration) in &schedule.timetable {
+        let end = start + duration;
+        intervals[job_index] = Some(match intervals[job_index] {
+            Some((first, last)) => (cmp::min(first, start), cmp::max(last, end)),
+            None => (start, end),
+        });
+    }
+
+    let mut graph = vec![Vec::new(); jobs.len()];
+    for i in 0..jobs.len() {
+        for j in i + 1..jobs.len() {
+            if let (Some(a), Some(b)) = (intervals[i], intervals[j]) {
+                if a.0 < b.1 && b.0 < a.1 {
+                    graph[i].push(j);
+                    graph[j].push(i);
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// Returns the criticality score of every job of a schedule, the fraction of
+/// random perturbations of the processing times in which the job determines
+/// the c max.
+///
+/// Every sample draws the processing time of each job from a normal
+/// distribution around its processing time with standard deviation
+/// `p_sigma`, rounded and cut at 0, and runs the jobs in the given order. The
+/// job reaching the c max (the first one in the schedule on ties) is the
+/// critical job of the sample, so the scores sum to 1.
+///
+/// # Panics
+///
+/// Panics if `n_samples` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::criticality_scores;
+/// // Without noise the second job always ends the schedule
+/// let js = JobList::new(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]);
+/// assert_eq!(criticality_scores(&js, 0, 10, 0), vec![0.0, 1.0]);
+/// ```
+pub fn criticality_scores(
+    schedule: &JobList,
+    p_sigma: u32,
+    n_samples: usize,
+    seed: u64,
+) -> Vec<f64> {
+    assert!(n_samples > 0, "at least one sample is required");
+    let mut rng = Rng::new(seed);
+    let mut counts = vec![0; schedule.jobs.len()];
+    for _ in 0..n_samples {
+        let mut t: u32 = 0;
+        let mut c_max = 0;
+        let mut critical = None;
+        for (i, job) in schedule.jobs.iter().enumerate() {
+            // Box-Muller transform of two uniform samples
+            let u = 1.0 - rng.next_f64();
+            let v = rng.next_f64();
+            let z = (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos();
+            let p = (job.processing_time as f64 + p_sigma as f64 * z)
+                .round()
+                .max(0.0) as u32;
+            t = cmp::max(t, job.delivery_time) + p;
+            if critical.is_none() || t + job.cooldown_time > c_max {
+                c_max = t + job.cooldown_time;
+                critical = Some(i);
+            }
+        }
+        if let Some(i) = critical {
+            counts[i] += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .map(|count| count as f64 / n_samples as f64)
+        .collect()
+}
+
+/// Returns the matrix of the c max of every pair of jobs run alone,
+/// `matrix[i][j]` for job `i` followed by job `j`.
+///
+/// The diagonal holds the c max of every job alone, `r + p + q`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::pairwise_c_max_matrix;
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(0, 5, 10)];
+/// assert_eq!(pairwise_c_max_matrix(&jobs), vec![vec![6, 20], vec![15, 15]]);
+/// ```
+pub fn pairwise_c_max_matrix(jobs: &[Job]) -> Vec<Vec<u32>> {
+    (0..jobs.len())
+        .map(|i| {
+            (0..jobs.len())
+                .map(|j| {
+                    if i == j {
+                        jobs[i].total_time()
+                    } else {
+                        JobList::new(vec![jobs[i], jobs[j]]).c_max()
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the total weighted tardiness `sum(w_j * max(0, C_j - d_j))` of
+/// the jobs run in the given order, where `C_j` is the time job `j` is done
+/// on the machine (without its cooldown time), `d_j = due_dates[j]` and
+/// `w_j = weights[j]` for the job at position `j` of the schedule.
+///
+/// # Panics
+///
+/// Panics if there is not one due date and one weight per job.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::total_weighted_tardiness;
+/// let js = JobList::new(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]);
+/// // The second job is done at 10, 3 late
+/// assert_eq!(total_weighted_tardiness(&js, &[5, 7], &[1, 4]), 12);
+/// ```
+pub fn total_weighted_tardiness(schedule: &JobList, due_dates: &[u32], weights: &[u32]) -> u64 {
+    assert_eq!(
+        schedule.jobs.len(),
+        due_dates.len(),
+        "every job needs a due date"
+    );
+    assert_eq!(
+        schedule.jobs.len(),
+        weights.len(),
+        "every job needs a weight"
+    );
+    let mut t = 0;
+    schedule
+        .jobs
+        .iter()
+        .enumerate()
+        .map(|(j, job)| {
+            t = cmp::max(t, job.delivery_time) + job.processing_time;
+            weights[j] as u64 * t.saturating_sub(due_dates[j]) as u64
+        })
+        .sum()
+}
+
+/// Returns the positions of the jobs of `schedule` in the order they are
+/// completely done, that is done on the machine and cooled down. This may
+/// differ from the order they are run in, a short job with a long cooldown
+/// time can be done after the jobs run after it. Ties keep the order they
+/// are run in.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::{completion_order, completion_rank};
+/// // Done at 11 and 7
+/// let js = JobList::new(vec![Job::new(0, 5, 6), Job::new(0, 2, 0)]);
+/// assert_eq!(completion_order(&js), vec![1, 0]);
+/// assert_eq!(completion_rank(&js), vec![1, 0]);
+/// ```
+pub fn completion_order(schedule: &JobList) -> Vec<usize> {
+    let mut t = 0;
+    let completion_times: Vec<u32> = schedule
+        .iter()
+        .map(|job| {
+            t = cmp::max(t, job.delivery_time) + job.processing_time;
+            t + job.cooldown_time
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..completion_times.len()).collect();
+    order.sort_by_key(|&j| completion_times[j]);
+    order
+}
+
+/// Returns the rank of every job of `schedule` in [`completion_order`],
+/// 0 for the job that is done first.
+pub fn completion_rank(schedule: &JobList) -> Vec<usize> {
+    let order = completion_order(schedule);
+    let mut rank = vec![0; order.len()];
+    for (r, &j) in order.iter().enumerate() {
+        rank[j] = r;
+    }
+    rank
+}
+
+/// Splits a schedule into chains of `chain_length` consecutive jobs, the
+/// last chain holds the remaining jobs and may be shorter.
+///
+/// # Panics
+///
+/// Panics if `chain_length` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::split_into_chains;
+/// let js = JobList::new(vec![Job::new(0, 1, 0), Job::new(1, 1, 0), Job::new(2, 1, 0)]);
+/// let chains = split_into_chains(&js, 2);
+/// assert_eq!(chains[0].jobs, vec![Job::new(0, 1, 0), Job::new(1, 1, 0)]);
+/// assert_eq!(chains[1].jobs, vec![Job::new(2, 1, 0)]);
+/// ```
+pub fn split_into_chains(schedule: &JobList, chain_length: usize) -> Vec<JobList> {
+    assert!(chain_length > 0, "the chain length must be positive");
+    schedule
+        .jobs
+        .chunks(chain_length)
+        .map(|chain| JobList::new(chain.to_vec()))
+        .collect()
+}
+
+/// C max of the Schrage algorithm where ready jobs with the same cooldown
+/// time are run in the order they became ready, and jobs delivered at the
+/// same time in the given order, as with a plain priority queue.
+fn insertion_order_c_max(jobs: &[Job]) -> u32 {
+    let mut shortest_delivery_jobs: Vec<usize> = (0..jobs.len()).collect();
+    shortest_delivery_jobs.sort_by_key(|&i| jobs[i].delivery_time);
+    let mut next = 0;
+    let mut ready_to_run: Vec<usize> = Vec::new();
+    let mut t = 0;
+    let mut c_max = 0;
+    while next < jobs.len() || !ready_to_run.is_empty() {
+        while next < jobs.len() && jobs[shortest_delivery_jobs[next]].delivery_time <= t {
+            ready_to_run.push(shortest_delivery_jobs[next]);
+            next += 1;
+        }
+        if ready_to_run.is_empty() {
+            t = jobs[shortest_delivery_jobs[next]].delivery_time;
+            continue;
+        }
+        let position = (0..ready_to_run.len())
+            .max_by_key(|&k| (jobs[ready_to_run[k]].cooldown_time, cmp::Reverse(k)))
+            .unwrap();
+        let job = jobs[ready_to_run.remove(position)];
+        t += job.processing_time;
+        c_max = cmp::max(c_max, t + job.cooldown_time);
+    }
+    c_max
+}
+
+/// Number of random insertion orderings drawn per job by
+/// [`insertion_order_sensitivity_score`].
+pub const INSERTION_ORDER_SAMPLES: usize = 100;
+
+/// Measures how much the c max of the Schrage algorithm depends on the order
+/// in which jobs delivered at the same time enter its ready queue.
+///
+/// [`schrage`] breaks ties between ready jobs by their processing and
+/// delivery times, so its result never depends on the input order. This
+/// measures a plain priority queue instead, where ready jobs with the same
+/// cooldown time are run in the order they were inserted. For every job,
+/// [`INSERTION_ORDER_SAMPLES`] random insertion orderings of it and the
+/// other jobs delivered at the same time (its peers) are drawn, and in each
+/// of them the job is moved to a random other position among its peers.
+/// The score is the fraction of those moves that change the c max. Jobs
+/// without peers score 0. The orderings are drawn from a fixed seed, so the
+/// scores are reproducible.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::insertion_order_sensitivity_score;
+/// // Running the short job first lets the urgent job delivered at 1 go next
+/// let jobs = vec![Job::new(0, 5, 10), Job::new(0, 1, 10), Job::new(1, 1, 20)];
+/// assert_eq!(insertion_order_sensitivity_score(&jobs), vec![1.0, 1.0, 0.0]);
+/// ```
+pub fn insertion_order_sensitivity_score(jobs: &[Job]) -> Vec<f64> {
+    let mut rng = Rng::new(0);
+    (0..jobs.len())
+        .map(|i| {
+            // Positions of the job and its peers in the input
+            let slots: Vec<usize> = (0..jobs.len())
+                .filter(|&k| jobs[k].delivery_time == jobs[i].delivery_time)
+                .collect();
+            if slots.len() < 2 {
+                return 0.0;
+            }
+            let changed = (0..INSERTION_ORDER_SAMPLES)
+                .filter(|_| {
+                    let mut group = slots.clone();
+                    rng.shuffle(&mut group);
+                    let from = group.iter().position(|&k| k == i).unwrap();
+                    let to = (from + 1 + rng.gen_index(group.len() - 1)) % group.len();
+                    let mut moved_group = group.clone();
+                    moved_group.remove(from);
+                    moved_group.insert(to, i);
+
+                    let ordering = |group: &[usize]| {
+                        let mut ordered = jobs.to_vec();
+                        for (&slot, &k) in slots.iter().zip(group) {
+                            ordered[slot] = jobs[k];
+                        }
+                        insertion_order_c_max(&ordered)
+                    };
+                    ordering(&group) != ordering(&moved_group)
+                })
+                .count();
+            changed as f64 / INSERTION_ORDER_SAMPLES as f64
+        })
+        .collect()
+}
+
+/// Returns every pair `(i, j)` of jobs such that running `jobs[i]` right
+/// before `jobs[j]` is never worse than the other way around, whenever and
+/// wherever in a schedule the two are run.
+///
+/// That is the case if `jobs[i]` is delivered no later and cools down no
+/// shorter than `jobs[j]`: the pair then leaves the machine no later and
+/// neither job cools down later than the last one of the swapped pair.
+/// Pairs of jobs with the same delivery and cooldown times are returned
+/// both ways.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::analysis::dominated_pairs;
+/// let jobs = vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)];
+/// assert_eq!(dominated_pairs(&jobs), vec![(0, 1)]);
+/// ```
+pub fn dominated_pairs(jobs: &[Job]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, a) in jobs.iter().enumerate() {
+        for (j, b) in jobs.iter().enumerate() {
+            if i != j && a.delivery_time <= b.delivery_time && a.cooldown_time >= b.cooldown_time {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// Returns the lowest c max of `schedule` after removing any `k` of its
+/// jobs, the others run in the same order.
+///
+/// Tries every set of `k` jobs, so it is only meant for small `k`.
+///
+/// # Panics
+///
+/// Panics if `k` is not smaller than the number of jobs.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::min_c_max_after_removing_k;
+/// let js = JobList::new(vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)]);
+/// assert_eq!(min_c_max_after_removing_k(&js, 0), js.c_max());
+/// // Without the last job
+/// assert_eq!(min_c_max_after_removing_k(&js, 1), 23);
+/// ```
+pub fn min_c_max_after_removing_k(schedule: &JobList, k: usize) -> u32 {
+    assert!(
+        k < schedule.jobs.len(),
+        "at least one job has to be left after removing k jobs"
+    );
+
+    /// Lowest c max of `kept` followed by the jobs from `next` on,
+    /// `k` of which are removed.
+    fn remove(jobs: &[Job], next: usize, k: usize, kept: &mut Vec<Job>) -> u32 {
+        if k == 0 {
+            let start = kept.len();
+            kept.extend_from_slice(&jobs[next..]);
+            let c_max = JobList::new(kept.clone()).c_max();
+            kept.truncate(start);
+            return c_max;
+        }
+        if jobs.len() - next == k {
+            return JobList::new(kept.clone()).c_max();
+        }
+        let without = remove(jobs, next + 1, k - 1, kept);
+        kept.push(jobs[next]);
+        let with = remove(jobs, next + 1, k, kept);
+        kept.pop();
+        cmp::min(without, with)
+    }
+
+    remove(&schedule.jobs, 0, k, &mut Vec::new())
+}
+
+/// Returns the Kendall tau distance between a schedule and the order by
+/// descending cooldown time, the order Schrage runs jobs delivered together.
+///
+/// That is the number of pairs of jobs run in the opposite order, a job
+/// before another one with a longer cooldown time. Jobs with the same
+/// cooldown time are never counted, so the distance is 0 for any list
+/// sorted by descending cooldown time and `n * (n - 1) / 2` for `n` jobs
+/// with different cooldown times sorted the other way around.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::kendall_tau_distance_to_sorted;
+/// let js = JobList::new(vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)]);
+/// assert_eq!(kendall_tau_distance_to_sorted(&js), 2);
+/// ```
+pub fn kendall_tau_distance_to_sorted(schedule: &JobList) -> usize {
+    let jobs = &schedule.jobs;
+    (0..jobs.len())
+        .map(|i| {
+            jobs[i + 1..]
+                .iter()
+                .filter(|later| later.cooldown_time > jobs[i].cooldown_time)
+                .count()
+        })
+        .sum()
+}
+
+/// Returns the mean Kendall tau distance over all pairs of `schedules`,
+/// a measure of how different the schedules are. 0 if there are fewer than
+/// two schedules.
+///
+/// The distance of two schedules is the number of pairs of jobs they run in
+/// the opposite order, equal jobs are matched like in [`jobs_to_permutation`].
+///
+/// # Panics
+///
+/// Panics if the schedules are not all permutations of the same jobs.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::analysis::mean_pairwise_kendall_tau;
+/// let a = JobList::new(vec![Job::new(0, 1, 0), Job::new(1, 1, 0), Job::new(2, 1, 0)]);
+/// let b = JobList::new(vec![a.jobs[1], a.jobs[0], a.jobs[2]]);
+/// assert_eq!(mean_pairwise_kendall_tau(&[a.clone(), a.clone()]), 0.0);
+/// // 0, 1 and 1 swapped pairs
+/// assert_eq!(mean_pairwise_kendall_tau(&[a.clone(), a, b]), 2.0 / 3.0);
+/// ```
+pub fn mean_pairwise_kendall_tau(schedules: &[JobList]) -> f64 {
+    let mut total = 0;
+    let mut pairs = 0;
+    for (i, a) in schedules.iter().enumerate() {
+        for b in &schedules[i + 1..] {
+            let positions = jobs_to_permutation(b, &a.jobs)
+                .expect("the schedules must be permutations of the same jobs");
+            // Pairs of jobs of a whose positions in b are the other way around
+            total += (0..positions.len())
+                .map(|k| {
+                    positions[k + 1..]
+                        .iter()
+                        .filter(|&&later| later < positions[k])
+                        .count()
+                })
+                .sum::<usize>();
+            pairs += 1;
+        }
+    }
+    if pairs == 0 {
+        0.0
+    } else {
+        total as f64 / pairs as f64
+    }
+}
+
+/// Returns the position in `schedule` of every job of `original`, or `None`
+/// if `schedule` is not a permutation of `original`.
+///
+/// Equal jobs are matched in the order they appear in both.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::schrage;
+/// use proc_opt::schrage::analysis::jobs_to_permutation;
+/// let jobs = vec![Job::new(10, 5, 7), Job::new(0, 6, 17), Job::new(13, 6, 26)];
+/// let schedule = schrage(&JobList::new(jobs.clone())).job_list;
+/// assert_eq!(jobs_to_permutation(&schedule, &jobs), Some(vec![1, 0, 2]));
+/// assert_eq!(jobs_to_permutation(&schedule, &jobs[1..]), None);
+/// ```
+pub fn jobs_to_permutation(schedule: &JobList, original: &[Job]) -> Option<Vec<usize>> {
+    if schedule.jobs.len() != original.len() {
+        return None;
+    }
+    let mut used = vec![false; original.len()];
+    original
+        .iter()
+        .map(|job| {
+            let position =
+                (0..schedule.jobs.len()).find(|&k| !used[k] && schedule.jobs[k] == *job)?;
+            used[position] = true;
+            Some(position)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_jobs() -> Vec<Job> {
+        vec![
+            Job::new(0, 6, 17),  // 6
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(30, 2, 0),  // 7
+        ]
+    }
+
+    #[test]
+    fn test_schrage_with_runtime_profiling() {
+        let jobs = example_jobs();
+        let (result, profile) = schrage_with_runtime_profiling(jobs.clone());
+        assert_eq!(result, schrage(&JobList::new(jobs.clone())).job_list);
+        assert_eq!(profile.jobs_processed, jobs.len());
+        assert!(profile.idle_skips <= jobs.len());
+        assert!(profile.ready_jobs_scanned >= jobs.len());
+        assert_eq!(
+            profile.display(),
+            "jobs processed: 7, queue operations: 14, ready jobs scanned: 10, idle skips: 1"
+        );
+    }
+
+    #[test]
+    fn test_schrage_with_runtime_profiling_scans() {
+        // Every job is ready at once, the queue shrinks by one every pick
+        let jobs: Vec<Job> = (0..10).map(|q| Job::new(0, 1, q)).collect();
+        let (_, profile) = schrage_with_runtime_profiling(jobs);
+        assert_eq!(profile.ready_jobs_scanned, 10 * 11 / 2);
+        assert_eq!(profile.idle_skips, 0);
+
+        // Every job is done before the next one is delivered
+        let jobs: Vec<Job> = (0..10).map(|r| Job::new(r * 5, 2, 3)).collect();
+        let (_, profile) = schrage_with_runtime_profiling(jobs);
+        assert_eq!(profile.ready_jobs_scanned, 10);
+        assert_eq!(profile.idle_skips, 9);
+    }
+
+    #[test]
+    fn test_schrage_with_runtime_profiling_empty() {
+        let (result, profile) = schrage_with_runtime_profiling(Vec::new());
+        assert!(result.jobs.is_empty());
+        assert_eq!(profile, ProfilingData::default());
+    }
+
+    #[test]
+    fn test_sample_random_c_max() {
+        let jobs = example_jobs();
+        let samples = sample_random_c_max(&jobs, 100, 1);
+        assert_eq!(samples.len(), 100);
+        assert_eq!(samples, sample_random_c_max(&jobs, 100, 1));
+        assert!(samples.iter().all(|&c| c >= 50));
+        assert!(sample_random_c_max(&jobs, 0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_schrage_below_random_mean() {
+        let mut rng = Rng::new(7);
+        let mut below = 0;
+        for seed in 0..20 {
+            let jobs: Vec<Job> = (0..20)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 100),
+                        rng.gen_range(1, 20),
+                        rng.gen_range(0, 100),
+                    )
+                })
+                .collect();
+            let samples = sample_random_c_max(&jobs, 50, seed);
+            let schrage_c_max = schrage(&JobList::new(jobs)).c_max();
+            if (schrage_c_max as f64) < c_max_mean(&samples) {
+                below += 1;
+            }
+        }
+        assert!(below >= 18);
+    }
+
+    #[test]
+    fn test_c_max_mean_and_std() {
+        assert_eq!(c_max_mean(&[7]), 7.0);
+        assert_eq!(c_max_std(&[7]), 0.0);
+        assert_eq!(c_max_mean(&[2, 4, 4, 4, 5, 5, 7, 9]), 5.0);
+        assert_eq!(c_max_std(&[2, 4, 4, 4, 5, 5, 7, 9]), 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_c_max_mean_empty() {
+        c_max_mean(&[]);
+    }
+
+    #[test]
+    fn test_compute_expected_c_max_distribution() {
+        let jobs = example_jobs();
+        let c_max = JobList::new(jobs.clone()).c_max();
+        let fixed = jobs.iter().map(|j| (j.processing_time, j.processing_time));
+        let samples = compute_expected_c_max_distribution(jobs.clone(), fixed.collect(), 20, 3);
+        assert_eq!(
+            c_max_distribution_statistics(&samples),
+            (c_max as f64, 0.0, c_max, c_max)
+        );
+
+        // Wider distributions give a larger mean, whether they only grow
+        // upwards or around the same processing time
+        let widened = |w: u32, below: u32| {
+            let ranges = jobs
+                .iter()
+                .map(|j| {
+                    (
+                        j.processing_time - below.min(j.processing_time),
+                        j.processing_time + w,
+                    )
+                })
+                .collect();
+            let samples = compute_expected_c_max_distribution(jobs.clone(), ranges, 2000, 1);
+            c_max_distribution_statistics(&samples)
+        };
+        let upwards: Vec<_> = [0, 2, 5, 10].iter().map(|&w| widened(w, 0)).collect();
+        let around: Vec<_> = [0, 2, 5, 10].iter().map(|&w| widened(w, w)).collect();
+        for stats in [upwards, around] {
+            assert_eq!(stats[0], (c_max as f64, 0.0, c_max, c_max));
+            for pair in stats.windows(2) {
+                assert!(pair[1].0 > pair[0].0);
+                assert!(pair[1].1 > pair[0].1);
+                assert!(pair[1].3 >= pair[0].3);
+            }
+        }
+        assert!(compute_expected_c_max_distribution(jobs, vec![(1, 2); 7], 0, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_compute_expected_c_max_distribution_missing_distribution() {
+        compute_expected_c_max_distribution(example_jobs(), vec![(1, 2)], 1, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_c_max_distribution_statistics_empty() {
+        c_max_distribution_statistics(&[]);
+    }
+
+    #[test]
+    fn test_build_conflict_graph_non_preemptive() {
+        let jobs = example_jobs();
+        let schedule = schrage(&JobList::new(jobs.clone())).job_list;
+        // Every job in a single segment, back to back or with idle time
+        let mut t = 0;
+        let timetable = schedule
+            .jobs
+            .iter()
+            .enumerate()
+            .map(|(i, job)| {
+                let start = cmp::max(t, job.delivery_time);
+                t = start + job.processing_time;
+                (start, i, job.processing_time)
+            })
+            .collect();
+        let schedule = JobSchedule::new(schedule.jobs, timetable);
+        let graph = build_conflict_graph(&schedule.jobs, &schedule);
+        assert_eq!(graph.len(), jobs.len());
+        assert!(graph.iter().all(|neighbours| neighbours.is_empty()));
+    }
+
+    #[test]
+    fn test_build_conflict_graph_two_preemptions() {
+        let jobs = vec![Job::new(0, 10, 1), Job::new(2, 2, 10), Job::new(6, 2, 10)];
+        let schedule = schrage_preemptive(&JobList::new(jobs.clone()));
+        // Job 0 is preempted by job 1 and then by job 2
+        assert_eq!(
+            schedule.timetable,
+            vec![(0, 0, 2), (2, 1, 2), (4, 0, 2), (6, 2, 2), (8, 0, 6)]
+        );
+        assert_eq!(
+            build_conflict_graph(&jobs, &schedule),
+            vec![vec![1, 2], vec![0], vec![0]]
+        );
+        assert!(build_conflict_graph(&[], &JobSchedule::new(Vec::new(), Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn test_criticality_scores() {
+        let js = JobList::new(example_jobs());
+        let scores = criticality_scores(&js, 2, 500, 3);
+        assert_eq!(scores.len(), 7);
+        assert!((scores.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(scores.iter().all(|&s| (0.0..=1.0).contains(&s)));
+        assert_eq!(scores, criticality_scores(&js, 2, 500, 3));
+        // Without noise the job reaching the c max of 53 is always critical
+        let exact = criticality_scores(&js, 0, 10, 3);
+        assert_eq!(exact, vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_pairwise_c_max_matrix() {
+        let jobs = example_jobs();
+        let matrix = pairwise_c_max_matrix(&jobs);
+        assert_eq!(matrix.len(), jobs.len());
+        for i in 0..jobs.len() {
+            assert_eq!(matrix[i].len(), jobs.len());
+            assert_eq!(matrix[i][i], jobs[i].total_time());
+            for j in 0..jobs.len() {
+                if i != j {
+                    assert_eq!(matrix[i][j], JobList::new(vec![jobs[i], jobs[j]]).c_max());
+                    // Never better than either job alone
+                    assert!(matrix[i][j] >= cmp::max(matrix[i][i], matrix[j][j]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pairwise_c_max_matrix_symmetric() {
+        // Delivered together with equal cooldown times, the order does not matter
+        let jobs = vec![Job::new(3, 5, 8), Job::new(3, 2, 8), Job::new(3, 7, 8)];
+        let matrix = pairwise_c_max_matrix(&jobs);
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, &c_max) in row.iter().enumerate() {
+                assert_eq!(c_max, matrix[j][i]);
+            }
+        }
+        assert_eq!(matrix[0][1], 3 + 5 + 2 + 8);
+        assert!(pairwise_c_max_matrix(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_total_weighted_tardiness() {
+        let js = JobList::new(example_jobs());
+        // Done at 6, 15, 21, 28, 32, 35 and 37
+        let on_time = [6, 15, 21, 28, 32, 35, 37];
+        assert_eq!(total_weighted_tardiness(&js, &on_time, &[5; 7]), 0);
+
+        let due_dates = [0, 10, 21, 30, 20, 40, 30];
+        let weights = [1, 2, 3, 4, 5, 6, 7];
+        // 6 * 1 + 5 * 2 + 12 * 5 + 7 * 7
+        assert_eq!(total_weighted_tardiness(&js, &due_dates, &weights), 125);
+        assert_eq!(
+            total_weighted_tardiness(&JobList::new(Vec::new()), &[], &[]),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_total_weighted_tardiness_missing_weight() {
+        total_weighted_tardiness(&JobList::new(example_jobs()), &[0; 7], &[1; 6]);
+    }
+
+    #[test]
+    fn test_completion_order_and_rank() {
+        let js = JobList::new(example_jobs());
+        // Done at 23, 22, 47, 52, 53, 43 and 37
+        assert_eq!(completion_order(&js), vec![1, 0, 6, 5, 2, 3, 4]);
+        let rank = completion_rank(&js);
+        assert_eq!(rank, vec![1, 0, 4, 5, 6, 3, 2]);
+        let mut sorted = rank.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..js.jobs.len()).collect::<Vec<_>>());
+        for (r, &j) in completion_order(&js).iter().enumerate() {
+            assert_eq!(rank[j], r);
+        }
+        assert!(completion_rank(&JobList::new(Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn test_split_into_chains() {
+        let js = JobList::new(example_jobs());
+        for chain_length in 1..9 {
+            let chains = split_into_chains(&js, chain_length);
+            assert_eq!(chains.len(), js.jobs.len().div_ceil(chain_length));
+            let (last, full) = chains.split_last().unwrap();
+            assert!(full.iter().all(|chain| chain.jobs.len() == chain_length));
+            assert_eq!(last.jobs.len(), js.jobs.len() - full.len() * chain_length);
+            let joined: JobList = chains.into_iter().flatten().collect();
+            assert_eq!(joined, js);
+        }
+        assert!(split_into_chains(&JobList::new(Vec::new()), 3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_into_chains_zero_length() {
+        split_into_chains(&JobList::new(example_jobs()), 0);
+    }
+
+    #[test]
+    fn test_insertion_order_sensitivity_unique_delivery_times() {
+        // Every job of the example but the last two is delivered alone
+        let scores = insertion_order_sensitivity_score(&example_jobs());
+        assert_eq!(scores.len(), 7);
+        assert!(scores[..5].iter().all(|&score| score == 0.0));
+        assert!(scores.iter().all(|&score| (0.0..=1.0).contains(&score)));
+
+        let mut rng = Rng::new(29);
+        let mut deliveries: Vec<u32> = (0..12).map(|r| r * 7).collect();
+        rng.shuffle(&mut deliveries);
+        let jobs: Vec<Job> = deliveries
+            .into_iter()
+            .map(|r| Job::new(r, rng.gen_range(1, 10), rng.gen_range(0, 5)))
+            .collect();
+        assert_eq!(insertion_order_sensitivity_score(&jobs), vec![0.0; 12]);
+    }
+
+    #[test]
+    fn test_insertion_order_sensitivity_matches_schrage() {
+        // With distinct cooldown times the queue order never matters
+        let jobs: Vec<Job> = (0..8).map(|q| Job::new(0, 3 + q % 3, q * 2)).collect();
+        assert_eq!(
+            insertion_order_c_max(&jobs),
+            schrage(&JobList::new(jobs.clone())).c_max()
+        );
+        assert_eq!(insertion_order_sensitivity_score(&jobs), vec![0.0; 8]);
+        assert!(insertion_order_sensitivity_score(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_insertion_order_sensitivity_partial() {
+        // The c max depends on which job delivered at 0 runs first, which
+        // moving a job changes in some orderings only
+        let jobs = vec![
+            Job::new(0, 5, 10),
+            Job::new(0, 1, 10),
+            Job::new(0, 2, 10),
+            Job::new(1, 1, 20),
+        ];
+        let scores = insertion_order_sensitivity_score(&jobs);
+        assert_eq!(scores, insertion_order_sensitivity_score(&jobs));
+        assert!(scores[..3].iter().all(|&score| 0.0 < score && score < 1.0));
+        assert_eq!(scores[3], 0.0);
+    }
+
+    #[test]
+    fn test_dominated_pairs_never_worse() {
+        for jobs in crate::io::test_instances() {
+            let pairs = dominated_pairs(&jobs);
+            for &(i, j) in &pairs {
+                let rest: Vec<Job> = (0..jobs.len())
+                    .filter(|&k| k != i && k != j)
+                    .map(|k| jobs[k])
+                    .collect();
+                // Run the pair adjacent in both orders at every position
+                for position in 0..=rest.len() {
+                    let with_pair = |first: Job, second: Job| {
+                        let mut sequence = rest.clone();
+                        sequence.insert(position, second);
+                        sequence.insert(position, first);
+                        JobList::new(sequence).c_max()
+                    };
+                    assert!(with_pair(jobs[i], jobs[j]) <= with_pair(jobs[j], jobs[i]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dominated_pairs_example() {
+        let pairs = dominated_pairs(&example_jobs());
+        // The first job is delivered first, it dominates the jobs
+        // with a shorter cooldown time
+        let first: Vec<usize> = pairs
+            .iter()
+            .filter(|&&(i, _)| i == 0)
+            .map(|&(_, j)| j)
+            .collect();
+        assert_eq!(first, vec![1, 5, 6]);
+        assert!(pairs.contains(&(5, 6)));
+        assert!(!pairs.contains(&(6, 5)));
+        assert!(dominated_pairs(&[]).is_empty());
+    }
+
+    /// Checks that `permutation` maps every job of `original` onto an equal
+    /// job of `schedule` and hits every position once.
+    fn assert_valid_permutation(schedule: &JobList, original: &[Job], permutation: &[usize]) {
+        assert_eq!(permutation.len(), original.len());
+        let mut positions = permutation.to_vec();
+        positions.sort_unstable();
+        assert_eq!(positions, (0..original.len()).collect::<Vec<_>>());
+        for (job, &k) in original.iter().zip(permutation) {
+            assert_eq!(schedule.jobs[k], *job);
+        }
+    }
+
+    #[test]
+    fn test_jobs_to_permutation_instances() {
+        let instances = vec![
+            vec![
+                Job::new(10, 5, 7),  // 1
+                Job::new(13, 6, 26), // 2
+                Job::new(11, 7, 24), // 3
+                Job::new(20, 4, 21), // 4
+                Job::new(30, 3, 8),  // 5
+                Job::new(0, 6, 17),  // 6
+                Job::new(30, 2, 0),  // 7
+            ],
+            vec![
+                Job::new(1, 5, 9), // 1
+                Job::new(4, 5, 4), // 2
+                Job::new(1, 4, 6), // 3
+                Job::new(7, 3, 3), // 4
+                Job::new(3, 6, 8), // 5
+                Job::new(4, 7, 1), // 6
+            ],
+            vec![
+                Job::new(52, 1, 56),   // 1
+                Job::new(70, 4, 93),   // 2
+                Job::new(112, 22, 79), // 3
+                Job::new(5, 14, 125),  // 4
+                Job::new(8, 16, 114),  // 5
+                Job::new(71, 7, 71),   // 6
+                Job::new(90, 2, 13),   // 7
+                Job::new(2, 20, 88),   // 8
+                Job::new(52, 20, 56),  // 9
+                Job::new(9, 28, 94),   // 10
+            ],
+            vec![
+                Job::new(162, 52, 241), // 1
+                Job::new(103, 68, 470), // 2
+                Job::new(39, 38, 340),  // 3
+                Job::new(394, 34, 400), // 4
+                Job::new(15, 86, 700),  // 5
+                Job::new(144, 73, 536), // 6
+                Job::new(51, 52, 403),  // 7
+                Job::new(233, 68, 23),  // 8
+                Job::new(183, 17, 641), // 9
+                Job::new(728, 18, 640), // 10
+                Job::new(667, 80, 92),  // 11
+                Job::new(57, 21, 76),   // 12
+                Job::new(35, 37, 386),  // 13
+                Job::new(567, 71, 618), // 14
+                Job::new(226, 5, 629),  // 15
+                Job::new(162, 80, 575), // 16
+                Job::new(588, 45, 632), // 17
+                Job::new(556, 23, 79),  // 18
+                Job::new(715, 8, 93),   // 19
+                Job::new(598, 45, 200), // 20
+            ],
+            example_jobs(),
+        ];
+        for jobs in instances {
+            let schedule = schrage(&JobList::new(jobs.clone())).job_list;
+            let permutation = jobs_to_permutation(&schedule, &jobs).unwrap();
+            assert_valid_permutation(&schedule, &jobs, &permutation);
+            let identity = jobs_to_permutation(&JobList::new(jobs.clone()), &jobs).unwrap();
+            assert_eq!(identity, (0..jobs.len()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_jobs_to_permutation_duplicates() {
+        let jobs = vec![Job::new(0, 2, 1), Job::new(0, 1, 5), Job::new(0, 2, 1)];
+        let schedule = JobList::new(vec![jobs[1], jobs[0], jobs[2]]);
+        assert_eq!(jobs_to_permutation(&schedule, &jobs), Some(vec![1, 0, 2]));
+        // One of the duplicates missing
+        let schedule = JobList::new(vec![jobs[1], jobs[0], jobs[1]]);
+        assert_eq!(jobs_to_permutation(&schedule, &jobs), None);
+    }
+
+    #[test]
+    fn test_jobs_to_permutation_not_a_permutation() {
+        let jobs = example_jobs();
+        let mut schedule = JobList::new(jobs.clone());
+        schedule.jobs[3].processing_time += 1;
+        assert_eq!(jobs_to_permutation(&schedule, &jobs), None);
+        assert_eq!(
+            jobs_to_permutation(&JobList::new(jobs[1..].to_vec()), &jobs),
+            None
+        );
+        assert_eq!(
+            jobs_to_permutation(&JobList::new(Vec::new()), &[]),
+            Some(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_min_c_max_after_removing_k() {
+        let js = JobList::new(example_jobs());
+        let n = js.jobs.len();
+        assert_eq!(min_c_max_after_removing_k(&js, 0), js.c_max());
+        // A single job left takes r + p + q
+        let single = js
+            .iter()
+            .map(|job| job.delivery_time + job.processing_time + job.cooldown_time)
+            .min()
+            .unwrap();
+        assert_eq!(min_c_max_after_removing_k(&js, n - 1), single);
+        let mut previous = js.c_max();
+        for k in 0..n {
+            let c_max = min_c_max_after_removing_k(&js, k);
+            assert!(c_max <= previous);
+            previous = c_max;
+        }
+    }
+
+    #[test]
+    fn test_min_c_max_after_removing_k_brute_force() {
+        let mut rng = Rng::new(4);
+        for _ in 0..20 {
+            let js: JobList = (0..rng.gen_range(1, 8))
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 50),
+                        rng.gen_range(1, 20),
+                        rng.gen_range(0, 50),
+                    )
+                })
+                .collect();
+            let n = js.jobs.len();
+            for k in 0..n {
+                // Every subset of n - k jobs, kept in order
+                let expected = (0u32..1 << n)
+                    .filter(|mask| mask.count_ones() as usize == n - k)
+                    .map(|mask| {
+                        (0..n)
+                            .filter(|&i| mask & (1 << i) != 0)
+                            .map(|i| js.jobs[i])
+                            .collect::<JobList>()
+                            .c_max()
+                    })
+                    .min()
+                    .unwrap();
+                assert_eq!(min_c_max_after_removing_k(&js, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_min_c_max_after_removing_all_jobs() {
+        min_c_max_after_removing_k(&JobList::new(example_jobs()), 7);
+    }
+
+    #[test]
+    fn test_kendall_tau_distance_to_sorted() {
+        let js = JobList::new(example_jobs());
+        let n = js.jobs.len();
+        let mut sorted = js.sorted_by_cooldown_time();
+        assert_eq!(
+            kendall_tau_distance_to_sorted(&JobList::new(sorted.clone())),
+            n * (n - 1) / 2
+        );
+        sorted.reverse();
+        assert_eq!(kendall_tau_distance_to_sorted(&JobList::new(sorted)), 0);
+        // Schrage runs every job delivered at 0 by descending cooldown time
+        let simultaneous = schrage(&JobList::new(vec![
+            Job::new(0, 5, 1),
+            Job::new(0, 2, 10),
+            Job::new(0, 3, 4),
+            Job::new(0, 1, 4),
+        ]))
+        .job_list;
+        assert_eq!(kendall_tau_distance_to_sorted(&simultaneous), 0);
+        assert_eq!(kendall_tau_distance_to_sorted(&JobList::new(Vec::new())), 0);
+    }
+
+    #[test]
+    fn test_kendall_tau_distance_to_sorted_swaps() {
+        // Every swap of neighbours with different cooldown times
+        // changes the distance by one
+        let mut rng = Rng::new(5);
+        let mut js = JobList::new(example_jobs());
+        for _ in 0..100 {
+            let i = rng.gen_index(js.jobs.len() - 1);
+            let before = kendall_tau_distance_to_sorted(&js) as i64;
+            js.jobs.swap(i, i + 1);
+            let after = kendall_tau_distance_to_sorted(&js) as i64;
+            assert_eq!((after - before).abs(), 1);
+        }
+    }
+
+    #[test]
+    fn test_mean_pairwise_kendall_tau() {
+        let js = JobList::new(example_jobs());
+        let n = js.jobs.len();
+        assert_eq!(mean_pairwise_kendall_tau(&vec![js.clone(); 5]), 0.0);
+        assert_eq!(mean_pairwise_kendall_tau(std::slice::from_ref(&js)), 0.0);
+        assert_eq!(mean_pairwise_kendall_tau(&[]), 0.0);
+        let mut reversed = js.clone();
+        reversed.jobs.reverse();
+        assert_eq!(
+            mean_pairwise_kendall_tau(&[js.clone(), reversed]),
+            (n * (n - 1) / 2) as f64
+        );
+
+        // More random swaps spread the schedules further apart
+        let mut rng = Rng::new(6);
+        let mut perturbed = |swaps: usize| {
+            (0..10)
+                .map(|_| {
+                    let mut schedule = js.clone();
+                    for _ in 0..swaps {
+                        let i = rng.gen_index(n);
+                        let j = rng.gen_index(n);
+                        schedule.jobs.swap(i, j);
+                    }
+                    schedule
+                })
+                .collect::<Vec<_>>()
+        };
+        let diversity: Vec<f64> = [0, 1, 3]
+            .iter()
+            .map(|&swaps| mean_pairwise_kendall_tau(&perturbed(swaps)))
+            .collect();
+        assert_eq!(diversity[0], 0.0);
+        assert!(diversity[1] > diversity[0]);
+        assert!(diversity[2] > diversity[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mean_pairwise_kendall_tau_different_jobs() {
+        let js = JobList::new(example_jobs());
+        mean_pairwise_kendall_tau(&[js, JobList::new(vec![Job::new(0, 1, 0)])]);
+    }
+
+    #[test]
+    fn test_modify_job_processing_time() {
+        let jobs = example_jobs();
+        let result = modify_job_processing_time(&jobs, 3, 1);
+        assert_eq!(result[3], Job::new(11, 1, 24));
+        assert_eq!(result.len(), jobs.len());
+        for (i, job) in result.iter().enumerate().filter(|&(i, _)| i != 3) {
+            assert_eq!(job, &jobs[i]);
+        }
+    }
+
+    #[test]
+    fn test_c_max_as_function_of_p_is_monotone() {
+        let jobs = example_jobs();
+        for job_index in 0..jobs.len() {
+            let result = c_max_as_function_of_p(&jobs, job_index, 0..30);
+            assert_eq!(result.len(), 30);
+            assert!(result.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    fn test_c_max_as_function_of_p_original() {
+        let jobs = example_jobs();
+        let result = c_max_as_function_of_p(&jobs, 0, 6..7);
+        assert_eq!(result, vec![53]);
+        assert!(c_max_as_function_of_p(&jobs, 0, 6..6).is_empty());
+    }
+
+    #[test]
+    fn test_schedule_fingerprint() {
+        let jobs = example_jobs();
+        let mut schedules = vec![JobList::new(Vec::new())];
+        for i in 0..jobs.len() {
+            for j in i + 1..jobs.len() {
+                let mut swapped = jobs.clone();
+                swapped.swap(i, j);
+                schedules.push(JobList::new(swapped));
+            }
+            schedules.push(JobList::new(jobs[..=i].to_vec()));
+            schedules.push(JobList::new(modify_job_processing_time(&jobs, i, 100)));
+            // Jobs differing only in their weight or due date
+            let mut weighted = jobs.clone();
+            weighted[i] = weighted[i].with_weight(2);
+            schedules.push(JobList::new(weighted));
+            let mut due = jobs.clone();
+            due[i] = due[i].with_due_date(0);
+            schedules.push(JobList::new(due.clone()));
+            due[i] = due[i].with_due_date(60);
+            schedules.push(JobList::new(due));
+        }
+        for (i, a) in schedules.iter().enumerate() {
+            assert_eq!(schedule_fingerprint(a), schedule_fingerprint(&a.clone()));
+            for b in &schedules[i + 1..] {
+                assert_eq!(a == b, schedule_fingerprint(a) == schedule_fingerprint(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let result = diagnose(example_jobs());
+        assert_eq!(result.n, 7);
+        assert_eq!(result.schrage_c_max, 53);
+        assert!(result.preemptive_lb <= result.schrage_c_max);
+        assert!(result.trivial_lb <= result.preemptive_lb);
+        assert!(!result.is_schrage_optimal);
+        let expected_gap = (53 - result.preemptive_lb) as f64 / result.preemptive_lb as f64 * 100.0;
+        assert!((result.gap_percent - expected_gap).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_diagnose_optimal() {
+        let jobs = vec![
+            Job::new(2, 20, 88),   // 8
+            Job::new(5, 14, 125),  // 4
+            Job::new(8, 16, 114),  // 5
+            Job::new(9, 28, 94),   // 10
+            Job::new(70, 4, 93),   // 2
+            Job::new(71, 7, 71),   // 6
+            Job::new(52, 1, 56),   // 1
+            Job::new(52, 20, 56),  // 9
+            Job::new(112, 22, 79), // 3
+            Job::new(90, 2, 13),   // 7
+        ];
+        let result = diagnose(jobs);
+        assert_eq!(result.schrage_c_max, 213);
+        assert_eq!(result.preemptive_lb, 213);
+        assert!(result.is_schrage_optimal);
+        assert_eq!(result.gap_percent, 0.0);
+    }
+
+    #[test]
+    fn test_diagnose_empty() {
+        let result = diagnose(Vec::new());
+        assert_eq!(result.n, 0);
+        assert_eq!(result.schrage_c_max, 0);
+        assert_eq!(result.preemptive_lb, 0);
+        assert_eq!(result.trivial_lb, 0);
+        assert_eq!(result.gap_percent, 0.0);
+        assert!(result.is_schrage_optimal);
+    }
+
+    #[test]
+    fn test_delivery_time_sensitivity() {
+        let js = JobList::new(example_jobs());
+        let c_max = js.c_max();
+        let result = delivery_time_sensitivity(&js);
+        assert_eq!(result.len(), js.jobs.len());
+        for (i, &slack) in result.iter().enumerate() {
+            let mut delayed = js.clone();
+            delayed.jobs[i].delivery_time += slack;
+            assert_eq!(delayed.c_max(), c_max);
+            delayed.jobs[i].delivery_time += 1;
+            assert!(delayed.c_max() > c_max);
+        }
+        // Job 1 starts the critical path right at its delivery time
+        assert_eq!(result[1], 0);
+    }
+
+    #[test]
+    fn test_delivery_time_sensitivity_empty() {
+        assert!(delivery_time_sensitivity(&JobList::new(Vec::new())).is_empty());
+    }
+}
@@ -0,0 +1,390 @@
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::{schrage_priority_score, schrage_tiebreaker_score};
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::{self, Ordering};
+
+/// Ready queue of [`schrage_generic`], `pop` decides which ready job runs next.
+///
+/// `T` is the type of the queued jobs, a [`Job`] or a job with the extra
+/// data the queue orders by.
+pub trait SchedulerHeap<T = Job> {
+    /// Adds a job that is ready to run.
+    fn push(&mut self, job: T);
+    /// Removes and returns the job to run next, `None` if there is none.
+    fn pop(&mut self) -> Option<T>;
+    /// Returns whether no job is ready to run.
+    fn is_empty(&self) -> bool;
+}
+
+/// [`Job`] ordered by the Schrage priority, the greatest one is run first:
+/// the longest cooldown time, on ties the shortest processing time, then
+/// the earliest delivery time.
+#[derive(Debug, Clone, Copy)]
+pub struct SchrageJob(pub Job);
+
+impl SchrageJob {
+    fn key(&self) -> (u32, cmp::Reverse<u32>, cmp::Reverse<u32>) {
+        (
+            schrage_priority_score(&self.0),
+            cmp::Reverse(schrage_tiebreaker_score(&self.0)),
+            cmp::Reverse(self.0.delivery_time),
+        )
+    }
+}
+
+impl PartialEq for SchrageJob {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for SchrageJob {}
+
+impl PartialOrd for SchrageJob {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SchrageJob {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl SchedulerHeap for BinaryHeap<SchrageJob> {
+    fn push(&mut self, job: Job) {
+        BinaryHeap::push(self, SchrageJob(job));
+    }
+
+    fn pop(&mut self) -> Option<Job> {
+        BinaryHeap::pop(self).map(|job| job.0)
+    }
+
+    fn is_empty(&self) -> bool {
+        BinaryHeap::is_empty(self)
+    }
+}
+
+/// Schrage algorithm with the ready queue given by `heap`.
+///
+/// Every time the machine is free the delivered jobs are pushed to `heap`
+/// and the job it pops is run, if it is empty the machine waits for the
+/// next delivery. With a `BinaryHeap<SchrageJob>` the result is the same
+/// as [`schrage`](crate::schrage::schrage). Jobs delivered at the same time
+/// are pushed in the order they are given.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::heap::{schrage_generic, SchrageJob};
+/// use proc_opt::schrage::jobs::Job;
+/// use std::collections::BinaryHeap;
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(0, 2, 10), Job::new(1, 1, 20)];
+/// let result = schrage_generic(jobs, BinaryHeap::<SchrageJob>::new());
+/// assert_eq!(
+///     result.jobs,
+///     vec![Job::new(0, 2, 10), Job::new(1, 1, 20), Job::new(0, 5, 1)]
+/// );
+/// ```
+pub fn schrage_generic<T, H>(jobs: Vec<T>, heap: H) -> JobList
+where
+    T: Copy + Into<Job>,
+    H: SchedulerHeap<T>,
+{
+    let mut heap = heap;
+    let mut shortest_delivery_jobs = jobs;
+    shortest_delivery_jobs.sort_by_key(|&job| job.into().delivery_time);
+    let mut next = 0;
+    let mut t: u32 = 0;
+    let mut pi = JobList::new(Vec::new());
+
+    while next < shortest_delivery_jobs.len() || !heap.is_empty() {
+        while next < shortest_delivery_jobs.len()
+            && shortest_delivery_jobs[next].into().delivery_time <= t
+        {
+            heap.push(shortest_delivery_jobs[next]);
+            next += 1;
+        }
+        match heap.pop() {
+            Some(job) => {
+                let job = job.into();
+                t = cmp::max(t, job.delivery_time) + job.processing_time;
+                pi.jobs.push(job);
+            }
+            None => t = shortest_delivery_jobs[next].into().delivery_time,
+        }
+    }
+    pi
+}
+
+/// Rule picking out of ready jobs with the same cooldown time in
+/// [`schrage_with_tiebreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreaker {
+    /// The shortest processing time, then the earliest delivery time,
+    /// like [`schrage`](crate::schrage::schrage).
+    #[default]
+    ProcessingTime,
+    /// The earliest delivery time.
+    DeliveryTime,
+    /// The longest processing time.
+    ProcessingTimeDescending,
+    /// The latest delivery time.
+    DeliveryTimeDescending,
+    /// The job given first.
+    InputOrder,
+}
+
+/// [`Job`] ordered by its cooldown time, on ties by `tiebreaker` and then
+/// by `index`, its position in the input. The greatest one is run first.
+#[derive(Debug, Clone, Copy)]
+pub struct SchrageJobWithTieBreaker {
+    pub job: Job,
+    pub index: usize,
+    pub tiebreaker: TieBreaker,
+}
+
+impl SchrageJobWithTieBreaker {
+    fn key(&self) -> (u32, (i64, i64), cmp::Reverse<usize>) {
+        let r = self.job.delivery_time as i64;
+        let p = self.job.processing_time as i64;
+        let tiebreaker = match self.tiebreaker {
+            TieBreaker::ProcessingTime => (-p, -r),
+            TieBreaker::DeliveryTime => (-r, 0),
+            TieBreaker::ProcessingTimeDescending => (p, 0),
+            TieBreaker::DeliveryTimeDescending => (r, 0),
+            TieBreaker::InputOrder => (0, 0),
+        };
+        (
+            schrage_priority_score(&self.job),
+            tiebreaker,
+            cmp::Reverse(self.index),
+        )
+    }
+}
+
+impl PartialEq for SchrageJobWithTieBreaker {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for SchrageJobWithTieBreaker {}
+
+impl PartialOrd for SchrageJobWithTieBreaker {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SchrageJobWithTieBreaker {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl From<SchrageJobWithTieBreaker> for Job {
+    fn from(ready: SchrageJobWithTieBreaker) -> Job {
+        ready.job
+    }
+}
+
+impl SchedulerHeap<SchrageJobWithTieBreaker> for BinaryHeap<SchrageJobWithTieBreaker> {
+    fn push(&mut self, job: SchrageJobWithTieBreaker) {
+        BinaryHeap::push(self, job);
+    }
+
+    fn pop(&mut self) -> Option<SchrageJobWithTieBreaker> {
+        BinaryHeap::pop(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BinaryHeap::is_empty(self)
+    }
+}
+
+/// Schrage algorithm breaking ties between ready jobs with the same
+/// cooldown time by `tiebreaker`.
+///
+/// The Schrage algorithm gives no guarantee on which of the tied jobs is
+/// better to run first, on some instances another rule gives a lower c max.
+/// With [`TieBreaker::ProcessingTime`] the result is the same as
+/// [`schrage`](crate::schrage::schrage).
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::heap::{schrage_with_tiebreaker, TieBreaker};
+/// use proc_opt::schrage::jobs::Job;
+/// let jobs = vec![Job::new(0, 10, 5), Job::new(0, 1, 5), Job::new(1, 1, 20)];
+/// let shortest = schrage_with_tiebreaker(jobs.clone(), TieBreaker::ProcessingTime);
+/// let longest = schrage_with_tiebreaker(jobs, TieBreaker::ProcessingTimeDescending);
+/// // The short job leaves the machine free when the urgent one is delivered
+/// assert_eq!(shortest.c_max(), 22);
+/// assert_eq!(longest.c_max(), 31);
+/// ```
+pub fn schrage_with_tiebreaker(jobs: Vec<Job>, tiebreaker: TieBreaker) -> JobList {
+    let jobs = jobs
+        .into_iter()
+        .enumerate()
+        .map(|(index, job)| SchrageJobWithTieBreaker {
+            job,
+            index,
+            tiebreaker,
+        })
+        .collect();
+    schrage_generic(jobs, BinaryHeap::<SchrageJobWithTieBreaker>::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::schrage::schrage;
+
+    /// Ready queue running the jobs in the order they became ready.
+    struct Fifo(std::collections::VecDeque<Job>);
+
+    impl SchedulerHeap for Fifo {
+        fn push(&mut self, job: Job) {
+            self.0.push_back(job);
+        }
+
+        fn pop(&mut self) -> Option<Job> {
+            self.0.pop_front()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    #[test]
+    fn test_schrage_generic_matches_schrage() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let result = schrage_generic(jobs.clone(), BinaryHeap::new());
+        assert_eq!(result, schrage(&JobList::new(jobs)).job_list);
+        assert_eq!(result.c_max(), 53);
+
+        let mut rng = Rng::new(41);
+        for _ in 0..20 {
+            let jobs: Vec<Job> = (0..15)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 30),
+                        rng.gen_range(1, 5),
+                        rng.gen_range(0, 5),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                schrage_generic(jobs.clone(), BinaryHeap::new()),
+                schrage(&JobList::new(jobs)).job_list
+            );
+        }
+    }
+
+    #[test]
+    fn test_schrage_generic_custom_heap() {
+        // Run in delivery order, the cooldown times are ignored
+        let jobs = vec![Job::new(3, 2, 9), Job::new(0, 5, 1), Job::new(1, 1, 20)];
+        let result = schrage_generic(jobs, Fifo(Default::default()));
+        assert_eq!(
+            result.jobs,
+            vec![Job::new(0, 5, 1), Job::new(1, 1, 20), Job::new(3, 2, 9)]
+        );
+        assert!(
+            schrage_generic(Vec::<Job>::new(), BinaryHeap::<SchrageJob>::new())
+                .jobs
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_schrage_job_order() {
+        let first = SchrageJob(Job::new(5, 3, 10));
+        assert!(first > SchrageJob(Job::new(0, 3, 9)));
+        assert!(first > SchrageJob(Job::new(0, 4, 10)));
+        assert!(first > SchrageJob(Job::new(6, 3, 10)));
+        assert_eq!(first, SchrageJob(Job::new(5, 3, 10).with_weight(2)));
+    }
+
+    #[test]
+    fn test_schrage_with_tiebreaker_processing_time() {
+        let mut rng = Rng::new(7);
+        for _ in 0..50 {
+            let jobs: Vec<Job> = (0..15)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 30),
+                        rng.gen_range(1, 5),
+                        rng.gen_range(0, 5),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                schrage_with_tiebreaker(jobs.clone(), TieBreaker::default()),
+                schrage(&JobList::new(jobs.clone())).job_list
+            );
+            for tiebreaker in [
+                TieBreaker::DeliveryTime,
+                TieBreaker::ProcessingTimeDescending,
+                TieBreaker::DeliveryTimeDescending,
+                TieBreaker::InputOrder,
+            ] {
+                let result = schrage_with_tiebreaker(jobs.clone(), tiebreaker);
+                assert_eq!(result.jobs.len(), jobs.len());
+            }
+        }
+        assert!(schrage_with_tiebreaker(Vec::new(), TieBreaker::InputOrder)
+            .jobs
+            .is_empty());
+    }
+
+    #[test]
+    fn test_schrage_with_tiebreaker_changes_c_max() {
+        // Both jobs delivered at 0 cool down for 5, the one run first
+        // decides when the job delivered at 1 can start
+        let jobs = vec![Job::new(0, 10, 5), Job::new(0, 1, 5), Job::new(1, 1, 20)];
+        let c_max = |tiebreaker| schrage_with_tiebreaker(jobs.clone(), tiebreaker).c_max();
+        assert_eq!(c_max(TieBreaker::ProcessingTime), 22);
+        assert_eq!(c_max(TieBreaker::ProcessingTimeDescending), 31);
+        assert_eq!(c_max(TieBreaker::InputOrder), 31);
+        let swapped = vec![jobs[1], jobs[0], jobs[2]];
+        assert_eq!(
+            schrage_with_tiebreaker(swapped, TieBreaker::InputOrder).c_max(),
+            22
+        );
+    }
+
+    #[test]
+    fn test_schrage_job_with_tiebreaker_order() {
+        let job = |r, p, index, tiebreaker| SchrageJobWithTieBreaker {
+            job: Job::new(r, p, 10),
+            index,
+            tiebreaker,
+        };
+        use TieBreaker::*;
+        assert!(job(0, 1, 1, ProcessingTime) > job(0, 2, 0, ProcessingTime));
+        assert!(job(0, 2, 1, ProcessingTimeDescending) > job(0, 1, 0, ProcessingTimeDescending));
+        assert!(job(0, 2, 1, DeliveryTime) > job(1, 1, 0, DeliveryTime));
+        assert!(job(1, 2, 1, DeliveryTimeDescending) > job(0, 1, 0, DeliveryTimeDescending));
+        assert!(job(5, 9, 0, InputOrder) > job(0, 1, 1, InputOrder));
+        let urgent = SchrageJobWithTieBreaker {
+            job: Job::new(0, 9, 11),
+            index: 5,
+            tiebreaker: InputOrder,
+        };
+        assert!(urgent > job(0, 1, 0, InputOrder));
+    }
+}
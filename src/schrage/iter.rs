@@ -0,0 +1,163 @@
+use crate::schrage::jobs::Job;
+use crate::schrage::{schrage_priority_score, schrage_tiebreaker_score};
+use alloc::vec::Vec;
+use core::cmp;
+
+/// State of a [`SchrageIterator`] between two jobs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchrageIteratorState {
+    /// Jobs delivered but not run yet, in the order they were delivered.
+    pub ready_to_run: Vec<Job>,
+    /// Jobs not delivered yet, by delivery time.
+    pub pending: Vec<Job>,
+    /// Time at which the machine is free.
+    pub current_time: u32,
+}
+
+/// Schrage algorithm run one job at a time.
+///
+/// Every item is the next job of the Schrage schedule and the time the
+/// machine is done with it. The jobs come in the same order as in
+/// [`schrage`](crate::schrage::schrage), [`SchrageIterator::state`] shows
+/// the ready and pending jobs in between.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::iter::SchrageIterator;
+/// use proc_opt::schrage::jobs::Job;
+/// let mut steps = SchrageIterator::new(vec![Job::new(0, 5, 1), Job::new(2, 2, 10)]);
+/// assert_eq!(steps.next(), Some((Job::new(0, 5, 1), 5)));
+/// assert_eq!(steps.state().current_time, 5);
+/// assert_eq!(steps.state().pending, vec![Job::new(2, 2, 10)]);
+/// assert_eq!(steps.next(), Some((Job::new(2, 2, 10), 7)));
+/// assert_eq!(steps.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchrageIterator {
+    state: SchrageIteratorState,
+}
+
+impl SchrageIterator {
+    /// Creates a new [`SchrageIterator`] at time 0.
+    pub fn new(jobs: Vec<Job>) -> SchrageIterator {
+        let mut pending = jobs;
+        pending.sort_by_key(|job| job.delivery_time);
+        SchrageIterator {
+            state: SchrageIteratorState {
+                ready_to_run: Vec::new(),
+                pending,
+                current_time: 0,
+            },
+        }
+    }
+
+    /// Returns the state of this [`SchrageIterator`] after the last job.
+    ///
+    /// Jobs delivered by the current time move to the ready jobs only when
+    /// the next job is picked.
+    pub fn state(&self) -> &SchrageIteratorState {
+        &self.state
+    }
+}
+
+impl Iterator for SchrageIterator {
+    type Item = (Job, u32);
+
+    fn next(&mut self) -> Option<(Job, u32)> {
+        let state = &mut self.state;
+        if state.ready_to_run.is_empty() {
+            // Skip to when the nearest job is available
+            let first = state.pending.first()?;
+            state.current_time = cmp::max(state.current_time, first.delivery_time);
+        }
+        let delivered = state
+            .pending
+            .iter()
+            .take_while(|job| job.delivery_time <= state.current_time)
+            .count();
+        state.ready_to_run.extend(state.pending.drain(..delivered));
+
+        let position = (0..state.ready_to_run.len())
+            .max_by_key(|&k| {
+                let job = &state.ready_to_run[k];
+                (
+                    schrage_priority_score(job),
+                    cmp::Reverse(schrage_tiebreaker_score(job)),
+                    cmp::Reverse(k),
+                )
+            })
+            .unwrap();
+        let job = state.ready_to_run.remove(position);
+        state.current_time += job.processing_time;
+        Some((job, state.current_time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::schrage::jobs::JobList;
+    use crate::schrage::schrage;
+
+    #[test]
+    fn test_schrage_iterator_matches_schrage() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let scheduled: JobList = SchrageIterator::new(jobs.clone())
+            .map(|(job, _)| job)
+            .collect();
+        assert_eq!(scheduled, schrage(&JobList::new(jobs)).job_list);
+
+        let mut rng = Rng::new(8);
+        for _ in 0..50 {
+            let jobs: Vec<Job> = (0..rng.gen_range(1, 20))
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 40),
+                        rng.gen_range(0, 5),
+                        rng.gen_range(0, 5),
+                    )
+                })
+                .collect();
+            let scheduled: JobList = SchrageIterator::new(jobs.clone())
+                .map(|(job, _)| job)
+                .collect();
+            assert_eq!(scheduled, schrage(&JobList::new(jobs)).job_list);
+        }
+        assert_eq!(SchrageIterator::new(Vec::new()).next(), None);
+    }
+
+    #[test]
+    fn test_schrage_iterator_times_and_state() {
+        let jobs = vec![Job::new(10, 5, 7), Job::new(0, 6, 17), Job::new(13, 6, 26)];
+        let mut steps = SchrageIterator::new(jobs.clone());
+        assert_eq!(steps.state().pending.len(), 3);
+        let mut t = 0;
+        let mut done = 0;
+        while let Some((job, time)) = steps.next() {
+            // The machine waits for the job if it is not delivered yet
+            t = cmp::max(t, job.delivery_time) + job.processing_time;
+            done += 1;
+            let state = steps.state();
+            assert_eq!(time, t);
+            assert_eq!(state.current_time, t);
+            assert_eq!(
+                state.ready_to_run.len() + state.pending.len(),
+                jobs.len() - done
+            );
+            assert!(state.ready_to_run.iter().all(|j| j.delivery_time <= t));
+        }
+        assert_eq!(t, 21);
+        assert!(steps.state().ready_to_run.is_empty());
+        assert!(steps.state().pending.is_empty());
+    }
+}
@@ -1,11 +1,20 @@
-use std::collections::HashMap;
-use std::{fmt, vec};
+use crate::schrage::schrage_preemptive;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{cmp, fmt};
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// Missing fields, such as the due date and weight, are those of Job::default
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Job {
-    pub delivery_time: u32,   // r
-    pub processing_time: u32, // p
-    pub cooldown_time: u32,   // q
+    pub delivery_time: u32,    // r
+    pub processing_time: u32,  // p
+    pub cooldown_time: u32,    // q
+    pub due_date: Option<u32>, // d
+    pub weight: u32,           // w
 }
 
 impl Job {
@@ -14,9 +23,95 @@ impl Job {
             delivery_time,
             processing_time,
             cooldown_time,
+            due_date: None,
+            weight: 1,
         }
     }
 
+    /// Returns this [`Job`] with the delivery time set to `v`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::Job;
+    /// let job = Job::default().with_processing_time(10).with_cooldown_time(5);
+    /// assert_eq!(job, Job::new(0, 10, 5));
+    /// assert_eq!(job.with_delivery_time(3), Job::new(3, 10, 5));
+    /// ```
+    pub fn with_delivery_time(mut self, v: u32) -> Self {
+        self.delivery_time = v;
+        self
+    }
+
+    /// Returns this [`Job`] with the processing time set to `v`.
+    pub fn with_processing_time(mut self, v: u32) -> Self {
+        self.processing_time = v;
+        self
+    }
+
+    /// Returns this [`Job`] with the cooldown time set to `v`.
+    pub fn with_cooldown_time(mut self, v: u32) -> Self {
+        self.cooldown_time = v;
+        self
+    }
+
+    /// Returns this [`Job`] with the due date set to `v`.
+    pub fn with_due_date(mut self, v: u32) -> Self {
+        self.due_date = Some(v);
+        self
+    }
+
+    /// Returns this [`Job`] with the weight set to `v`.
+    pub fn with_weight(mut self, v: u32) -> Self {
+        self.weight = v;
+        self
+    }
+
+    /// Returns whether `other` dominates this [`Job`]: it is delivered no
+    /// later, takes no shorter and cools down no shorter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::Job;
+    /// assert!(Job::new(5, 2, 1).is_dominated_by(&Job::new(0, 4, 20)));
+    /// assert!(!Job::new(0, 4, 20).is_dominated_by(&Job::new(5, 2, 1)));
+    /// ```
+    pub fn is_dominated_by(&self, other: &Job) -> bool {
+        other.delivery_time <= self.delivery_time
+            && other.processing_time >= self.processing_time
+            && other.cooldown_time >= self.cooldown_time
+    }
+
+    /// Returns the lateness `C - d` of this [`Job`] if it completes at
+    /// `completion_time`, negative if it completes before its due date.
+    ///
+    /// A job without a due date is never late nor early, its lateness is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::Job;
+    /// let job = Job::new(0, 5, 1).with_due_date(10);
+    /// assert_eq!(job.lateness(7), -3);
+    /// assert_eq!(job.tardiness(7), 0);
+    /// assert_eq!(job.lateness(12), 2);
+    /// assert_eq!(job.tardiness(12), 2);
+    /// assert_eq!(Job::new(0, 5, 1).lateness(12), 0);
+    /// ```
+    pub fn lateness(&self, completion_time: u32) -> i32 {
+        match self.due_date {
+            Some(due_date) => completion_time as i32 - due_date as i32,
+            None => 0,
+        }
+    }
+
+    /// Returns the tardiness `max(0, C - d)` of this [`Job`] if it completes
+    /// at `completion_time`, 0 if it has no due date.
+    pub fn tardiness(&self, completion_time: u32) -> u32 {
+        self.lateness(completion_time).max(0) as u32
+    }
+
     #[allow(dead_code)]
     pub fn total_time(&self) -> u32 {
         self.delivery_time + self.processing_time + self.cooldown_time
@@ -38,18 +133,221 @@ impl PartialEq for Job {
         self.delivery_time == other.delivery_time
             && self.processing_time == other.processing_time
             && self.cooldown_time == other.cooldown_time
+            && self.due_date == other.due_date
+            && self.weight == other.weight
+    }
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Job::new(0, 0, 0)
+    }
+}
+
+/// [`Job`] with an optional human readable label.
+///
+/// The label is only used for display, the algorithms take the plain
+/// jobs made by [`LabeledJob::into_job`].
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, LabeledJob};
+/// let job = LabeledJob::new("Machine setup", 0, 6, 17);
+/// assert_eq!(job.to_string(), "Machine setup (0, 6, 17)");
+/// assert_eq!(job.into_job(), Job::new(0, 6, 17));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LabeledJob {
+    pub label: Option<String>,
+    pub delivery_time: u32,   // r
+    pub processing_time: u32, // p
+    pub cooldown_time: u32,   // q
+}
+
+impl LabeledJob {
+    /// Creates a new [`LabeledJob`] with the given label.
+    pub fn new(
+        label: impl Into<String>,
+        delivery_time: u32,
+        processing_time: u32,
+        cooldown_time: u32,
+    ) -> LabeledJob {
+        LabeledJob {
+            label: Some(label.into()),
+            delivery_time,
+            processing_time,
+            cooldown_time,
+        }
+    }
+
+    /// Returns the label of this [`LabeledJob`], if it has one.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns the [`Job`] of this [`LabeledJob`], without the label.
+    pub fn into_job(self) -> Job {
+        Job::new(self.delivery_time, self.processing_time, self.cooldown_time)
+    }
+}
+
+impl From<Job> for LabeledJob {
+    fn from(job: Job) -> LabeledJob {
+        LabeledJob {
+            label: None,
+            delivery_time: job.delivery_time,
+            processing_time: job.processing_time,
+            cooldown_time: job.cooldown_time,
+        }
+    }
+}
+
+impl fmt::Display for LabeledJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let job = Job::new(self.delivery_time, self.processing_time, self.cooldown_time);
+        match &self.label {
+            Some(label) => write!(f, "{} {}", label, job),
+            None => write!(f, "{}", job),
+        }
     }
 }
 
+/// Returns the labels of the jobs, in the same order.
+///
+/// This is a function over labeled jobs rather than a method of
+/// [`JobList`]: a [`JobList`] holds plain [`Job`]s, which are `Copy` and
+/// have no label, so it has no labels to return.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{labels, Job, LabeledJob};
+/// let jobs = vec![LabeledJob::new("A", 0, 6, 17), LabeledJob::from(Job::new(10, 5, 7))];
+/// assert_eq!(labels(&jobs), vec![Some("A"), None]);
+/// ```
+pub fn labels(jobs: &[LabeledJob]) -> Vec<Option<&str>> {
+    jobs.iter().map(LabeledJob::label).collect()
+}
+
+/// Returns the jobs that are delivered at or before time `t`,
+/// i.e. the jobs that can be run at time `t`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{filter_by_delivery_before, Job};
+/// let jobs = vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)];
+/// let before: Vec<&Job> = filter_by_delivery_before(&jobs, 10).collect();
+/// assert_eq!(before, vec![&jobs[0], &jobs[1]]);
+/// ```
+pub fn filter_by_delivery_before(jobs: &[Job], t: u32) -> impl Iterator<Item = &Job> {
+    jobs.iter().filter(move |j| j.delivery_time <= t)
+}
+
+/// Returns the jobs that are delivered after time `t`,
+/// the complement of [`filter_by_delivery_before`].
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{filter_by_delivery_after, Job};
+/// let jobs = vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)];
+/// let after: Vec<&Job> = filter_by_delivery_after(&jobs, 10).collect();
+/// assert_eq!(after, vec![&jobs[2]]);
+/// ```
+pub fn filter_by_delivery_after(jobs: &[Job], t: u32) -> impl Iterator<Item = &Job> {
+    jobs.iter().filter(move |j| j.delivery_time > t)
+}
+
+/// Merges job lists into a single one sorted by delivery time, jobs
+/// delivered at the same time keep the order of `lists`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{merge_by_delivery_time, Job, JobList};
+/// let a = JobList::new(vec![Job::new(0, 6, 17), Job::new(13, 6, 26)]);
+/// let b = JobList::new(vec![Job::new(10, 5, 7)]);
+/// assert_eq!(
+///     merge_by_delivery_time(vec![a, b]).jobs,
+///     vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)]
+/// );
+/// ```
+pub fn merge_by_delivery_time(lists: Vec<JobList>) -> JobList {
+    let mut merged: JobList = lists.into_iter().flatten().collect();
+    merged.jobs.sort_by_key(|j| j.delivery_time);
+    merged
+}
+
+/// Groups the jobs by delivery time, the groups are sorted by delivery time
+/// and keep the given order of their jobs.
+///
+/// Every group holds the jobs that enter the ready queue of the Schrage
+/// algorithm at the same time.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{group_by_delivery, Job};
+/// let jobs = vec![Job::new(10, 5, 7), Job::new(0, 6, 17), Job::new(10, 2, 1)];
+/// assert_eq!(
+///     group_by_delivery(&jobs),
+///     vec![(0, vec![&jobs[1]]), (10, vec![&jobs[0], &jobs[2]])]
+/// );
+/// ```
+pub fn group_by_delivery(jobs: &[Job]) -> Vec<(u32, Vec<&Job>)> {
+    let mut by_delivery_time: Vec<&Job> = jobs.iter().collect();
+    by_delivery_time.sort_by_key(|j| j.delivery_time);
+    let mut groups: Vec<(u32, Vec<&Job>)> = Vec::new();
+    for job in by_delivery_time {
+        match groups.last_mut() {
+            Some((t, group)) if *t == job.delivery_time => group.push(job),
+            _ => groups.push((job.delivery_time, vec![job])),
+        }
+    }
+    groups
+}
+
+/// Returns the earliest time every job could start if the machine
+/// becomes available at `machine_available_at`.
+///
+/// The result holds `(job_index, start_time)` pairs sorted by start time,
+/// jobs with equal start times keep their original order.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{earliest_starts, Job};
+/// let jobs = vec![Job::new(13, 6, 26), Job::new(0, 6, 17), Job::new(10, 5, 7)];
+/// assert_eq!(earliest_starts(&jobs, 5), vec![(1, 5), (2, 10), (0, 13)]);
+/// ```
+pub fn earliest_starts(jobs: &[Job], machine_available_at: u32) -> Vec<(usize, u32)> {
+    let mut starts: Vec<(usize, u32)> = jobs
+        .iter()
+        .enumerate()
+        .map(|(i, j)| (i, cmp::max(machine_available_at, j.delivery_time)))
+        .collect();
+    starts.sort_by_key(|&(_, start)| start);
+    starts
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JobList {
     pub jobs: Vec<Job>,
 }
 
+/// One line `r p q` per job, the text format read by
+/// [`JobList::from_str`](core::str::FromStr::from_str).
 impl fmt::Display for JobList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for i in &self.jobs {
-            writeln!(f, "{}", i)?;
+            writeln!(
+                f,
+                "{} {} {}",
+                i.delivery_time, i.processing_time, i.cooldown_time
+            )?;
         }
         Ok(())
     }
@@ -84,6 +382,361 @@ impl JobList {
         by_cooldown_time.sort_by_key(|a| a.cooldown_time);
         by_cooldown_time
     }
+
+    /// Returns the Job List sorted by ascending total time `r + p + q` of
+    /// this [`JobList`].
+    pub fn sorted_by_total_time(&self) -> Vec<Job> {
+        let mut by_total_time = self.jobs.clone();
+        by_total_time.sort_by_key(Job::total_time);
+        by_total_time
+    }
+
+    /// Returns the Job List sorted by descending total time `r + p + q` of
+    /// this [`JobList`], jobs with equal total times keep their order.
+    pub fn sorted_by_total_time_desc(&self) -> Vec<Job> {
+        let mut by_total_time = self.jobs.clone();
+        by_total_time.sort_by_key(|a| cmp::Reverse(a.total_time()));
+        by_total_time
+    }
+
+    /// Returns the Job List sorted by ascending slack of this [`JobList`],
+    /// the slack of a job being how much its total time `r + p + q` is below
+    /// [`JobList::c_max_lower_bound`]. The jobs defining the bound come first.
+    pub fn sorted_by_slack_time(&self) -> Vec<Job> {
+        let lower_bound = self.c_max_lower_bound();
+        let mut by_slack_time = self.jobs.clone();
+        by_slack_time.sort_by_key(|a| lower_bound - a.total_time());
+        by_slack_time
+    }
+
+    /// Returns the c max of this [`JobList`] when run in the given order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the job list is empty.
+    pub fn c_max(&self) -> u32 {
+        let mut end_times = vec![0; self.jobs.len()];
+        let mut s = 0;
+        let mut sums = vec![0; self.jobs.len()];
+
+        for (i, job) in self.jobs.iter().enumerate() {
+            if job.delivery_time > s {
+                s = job.delivery_time + job.processing_time;
+            } else {
+                s += job.processing_time;
+            }
+            end_times[i] = s;
+        }
+
+        for (i, job) in self.jobs.iter().enumerate() {
+            sums[i] = job.cooldown_time + end_times[i];
+        }
+        *sums.iter().max().unwrap()
+    }
+
+    /// Returns the time every job of this [`JobList`] is done on the machine,
+    /// without its cooldown time, when run in the given order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let js = JobList::new(vec![Job::new(0, 6, 17), Job::new(10, 5, 7), Job::new(13, 6, 26)]);
+    /// assert_eq!(js.completion_times(), vec![6, 15, 21]);
+    /// ```
+    pub fn completion_times(&self) -> Vec<u32> {
+        let mut s = 0;
+        self.jobs
+            .iter()
+            .map(|job| {
+                s = cmp::max(s, job.delivery_time) + job.processing_time;
+                s
+            })
+            .collect()
+    }
+
+    /// Returns the flow time `C_j - r_j` of every job of this [`JobList`], the
+    /// time from its delivery until it is done on the machine, in order.
+    pub fn flow_times(&self) -> Vec<u32> {
+        self.completion_times()
+            .into_iter()
+            .zip(&self.jobs)
+            .map(|(c, job)| c - job.delivery_time)
+            .collect()
+    }
+
+    /// Returns the sum of the [`JobList::flow_times`] of this [`JobList`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let js = JobList::new(vec![Job::new(0, 6, 17), Job::new(4, 5, 7)]);
+    /// assert_eq!(js.flow_times(), vec![6, 7]);
+    /// assert_eq!(js.total_flow_time(), 13);
+    /// ```
+    pub fn total_flow_time(&self) -> u32 {
+        self.flow_times().iter().sum()
+    }
+
+    /// Returns the longest of the [`JobList::flow_times`] of this [`JobList`],
+    /// 0 if it is empty.
+    pub fn max_flow_time(&self) -> u32 {
+        self.flow_times().into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns the sum of [`Job::lateness`] of the jobs of this [`JobList`],
+    /// where `completion_times[j]` is the completion time of the `j`-th job.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one completion time per job.
+    pub fn total_lateness(&self, completion_times: &[u32]) -> i32 {
+        self.zip_completion_times(completion_times)
+            .map(|(job, c)| job.lateness(c))
+            .sum()
+    }
+
+    /// Returns the sum of [`Job::tardiness`] of the jobs of this [`JobList`],
+    /// where `completion_times[j]` is the completion time of the `j`-th job.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one completion time per job.
+    pub fn total_tardiness(&self, completion_times: &[u32]) -> u32 {
+        self.zip_completion_times(completion_times)
+            .map(|(job, c)| job.tardiness(c))
+            .sum()
+    }
+
+    /// Returns the largest [`Job::lateness`] of the jobs of this [`JobList`],
+    /// where `completion_times[j]` is the completion time of the `j`-th job.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the job list is empty or there is not one completion
+    /// time per job.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let js = JobList::new(vec![
+    ///     Job::new(0, 6, 17).with_due_date(10),
+    ///     Job::new(10, 5, 7).with_due_date(12),
+    /// ]);
+    /// let completion_times = js.completion_times();
+    /// assert_eq!(js.maximum_lateness(&completion_times), 3);
+    /// assert_eq!(js.total_lateness(&completion_times), -1);
+    /// assert_eq!(js.total_tardiness(&completion_times), 3);
+    /// ```
+    pub fn maximum_lateness(&self, completion_times: &[u32]) -> i32 {
+        self.zip_completion_times(completion_times)
+            .map(|(job, c)| job.lateness(c))
+            .max()
+            .unwrap()
+    }
+
+    fn zip_completion_times<'a>(
+        &'a self,
+        completion_times: &'a [u32],
+    ) -> impl Iterator<Item = (&'a Job, u32)> {
+        assert_eq!(
+            self.jobs.len(),
+            completion_times.len(),
+            "every job needs a completion time"
+        );
+        self.jobs.iter().zip(completion_times.iter().copied())
+    }
+
+    /// Returns this [`JobList`] with the earliest delivery time moved to 0,
+    /// every delivery time is lowered by the same offset `min(r_j)`.
+    ///
+    /// The jobs are run at the same times minus the offset, so the c max of
+    /// the result is the c max of this [`JobList`] minus the offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let js = JobList::new(vec![Job::new(1010, 5, 7), Job::new(1000, 6, 17)]);
+    /// let normalized = js.normalize();
+    /// assert_eq!(normalized.jobs, vec![Job::new(10, 5, 7), Job::new(0, 6, 17)]);
+    /// assert_eq!(normalized.c_max(), js.c_max() - 1000);
+    /// ```
+    pub fn normalize(&self) -> JobList {
+        let offset = self.jobs.iter().map(|j| j.delivery_time).min().unwrap_or(0);
+        self.jobs
+            .iter()
+            .map(|j| j.with_delivery_time(j.delivery_time - offset))
+            .collect()
+    }
+
+    /// Returns this [`JobList`] with every time of every job, including the
+    /// due dates, multiplied by `factor`. The c max is multiplied as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a time does not fit into a `u32`.
+    pub fn scale(&self, factor: u32) -> JobList {
+        let times = |t: u32| t.checked_mul(factor).expect("scaled time overflows u32");
+        self.jobs
+            .iter()
+            .map(|j| Job {
+                delivery_time: times(j.delivery_time),
+                processing_time: times(j.processing_time),
+                cooldown_time: times(j.cooldown_time),
+                due_date: j.due_date.map(times),
+                ..*j
+            })
+            .collect()
+    }
+
+    /// Returns the jobs of this [`JobList`] that are not dominated by another
+    /// one (see [`Job::is_dominated_by`]), sorted by delivery time. Out of
+    /// equal jobs only the first one is kept.
+    ///
+    /// Removing jobs can only shorten the optimal schedule, so the optimal
+    /// c max of the result is a lower bound for the original jobs. It is the
+    /// same when the dominated jobs fit into the schedule of the others,
+    /// e.g. a short job run while a job that dominates it cools down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let js = JobList::new(vec![Job::new(5, 2, 1), Job::new(0, 4, 20), Job::new(1, 6, 15)]);
+    /// assert_eq!(js.remove_dominated().jobs, vec![Job::new(0, 4, 20), Job::new(1, 6, 15)]);
+    /// ```
+    pub fn remove_dominated(&self) -> JobList {
+        let kept = self
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|&(j, job)| {
+                !self.jobs.iter().enumerate().any(|(k, other)| {
+                    k != j && job.is_dominated_by(other) && (other != job || k < j)
+                })
+            })
+            .map(|(_, job)| *job)
+            .collect();
+        JobList::new(JobList::new(kept).sorted_by_delivery_time())
+    }
+
+    /// Returns the lower bound `max(r_j + p_j + q_j)` on the optimal c max of
+    /// this [`JobList`], no job can be done and cooled down any earlier.
+    ///
+    /// Returns 0 if the job list is empty.
+    pub fn c_max_lower_bound(&self) -> u32 {
+        self.jobs.iter().map(Job::total_time).max().unwrap_or(0)
+    }
+
+    /// Returns the c max of the preemptive Schrage schedule (Jackson's
+    /// preemptive schedule) of this [`JobList`].
+    ///
+    /// It is at least `min(r) + sum(p) + min(q)` over every subset of jobs,
+    /// which makes it the tightest lower bound on the optimal c max known to
+    /// be computable in polynomial time, and never lower than
+    /// [`JobList::c_max_lower_bound`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let js = JobList::new(vec![Job::new(0, 5, 1), Job::new(0, 5, 10)]);
+    /// assert_eq!(js.c_max_lower_bound(), 15);
+    /// assert_eq!(js.c_max_lower_bound_2(), 15);
+    /// ```
+    pub fn c_max_lower_bound_2(&self) -> u32 {
+        if self.jobs.is_empty() {
+            return 0;
+        }
+        schrage_preemptive(self).c_max()
+    }
+
+    /// Returns how much longer the c max of this [`JobList`] is than the c max
+    /// of `other`, in percent of the latter. Negative if this one is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either job list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobList};
+    /// let heuristic = JobList::new(vec![Job::new(0, 5, 0), Job::new(0, 5, 15)]);
+    /// let optimal = JobList::new(vec![Job::new(0, 5, 15), Job::new(0, 5, 0)]);
+    /// // 25 against 20
+    /// assert_eq!(heuristic.optimality_gap(&optimal), 25.0);
+    /// assert!(optimal.dominates(&heuristic));
+    /// ```
+    pub fn optimality_gap(&self, other: &JobList) -> f64 {
+        let other_c_max = other.c_max() as f64;
+        (self.c_max() as f64 - other_c_max) / other_c_max * 100.0
+    }
+
+    /// Returns whether the c max of this [`JobList`] is not longer than
+    /// the c max of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either job list is empty.
+    pub fn dominates(&self, other: &JobList) -> bool {
+        self.c_max() <= other.c_max()
+    }
+
+    /// Returns an iterator over the jobs of this [`JobList`], in order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Job> {
+        self.jobs.iter()
+    }
+
+    /// Returns an iterator that allows modifying every job of this [`JobList`].
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, Job> {
+        self.jobs.iter_mut()
+    }
+}
+
+impl IntoIterator for JobList {
+    type Item = Job;
+    type IntoIter = vec::IntoIter<Job>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.jobs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a JobList {
+    type Item = &'a Job;
+    type IntoIter = core::slice::Iter<'a, Job>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.jobs.iter()
+    }
+}
+
+impl FromIterator<Job> for JobList {
+    fn from_iter<I: IntoIterator<Item = Job>>(iter: I) -> JobList {
+        JobList::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Job> for JobList {
+    fn extend<I: IntoIterator<Item = Job>>(&mut self, iter: I) {
+        self.jobs.extend(iter);
+    }
+}
+
+impl From<Vec<Job>> for JobList {
+    fn from(jobs: Vec<Job>) -> JobList {
+        JobList::new(jobs)
+    }
+}
+
+impl From<JobList> for Vec<Job> {
+    fn from(job_list: JobList) -> Vec<Job> {
+        job_list.jobs
+    }
 }
 
 impl PartialEq for JobList {
@@ -115,6 +768,17 @@ impl SchrageJobTable {
     ///
     /// Panics if the job list is empty.
     pub fn c_max(&self) -> u32 {
+        self.job_list.c_max()
+    }
+}
+
+pub struct PartTimeSchrageJobTable {
+    pub job_list: JobList,
+    pub time_table: BTreeMap<u32, u32>,
+}
+
+impl PartTimeSchrageJobTable {
+    pub fn c_max_wip(&self) -> u32 {
         let mut end_times = vec![0; self.job_list.jobs.len()];
         let mut s = 0;
         let mut sums = vec![0; self.job_list.jobs.len()];
@@ -135,30 +799,212 @@ impl SchrageJobTable {
     }
 }
 
-pub struct PartTimeSchrageJobTable {
-    pub job_list: JobList,
-    pub time_table: HashMap<u32, u32>,
+/// Preemptive schedule of a set of jobs on a single machine.
+///
+/// Every entry of the `timetable` is a `(start_time, job_index, duration)`
+/// triple describing one uninterrupted run of `jobs[job_index]`.
+/// Entries are ordered by their start time.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobSchedule {
+    pub jobs: Vec<Job>,
+    pub timetable: Vec<(u32, usize, u32)>,
 }
 
-impl PartTimeSchrageJobTable {
-    pub fn c_max_wip(&self) -> u32 {
-        let mut end_times = vec![0; self.job_list.jobs.len()];
-        let mut s = 0;
-        let mut sums = vec![0; self.job_list.jobs.len()];
+impl JobSchedule {
+    /// Creates a new [`JobSchedule`].
+    pub fn new(jobs: Vec<Job>, timetable: Vec<(u32, usize, u32)>) -> JobSchedule {
+        JobSchedule { jobs, timetable }
+    }
 
-        for (i, job) in self.job_list.jobs.iter().enumerate() {
-            if job.delivery_time > s {
-                s = job.delivery_time + job.processing_time;
-            } else {
-                s += job.processing_time;
+    /// Returns the c max of this [`JobSchedule`].
+    ///
+    /// Returns 0 if the timetable is empty.
+    pub fn c_max(&self) -> u32 {
+        let mut end_times = vec![0; self.jobs.len()];
+        for &(start, job_index, duration) in &self.timetable {
+            end_times[job_index] = cmp::max(end_times[job_index], start + duration);
+        }
+        self.timetable
+            .iter()
+            .map(|&(_, job_index, _)| end_times[job_index] + self.jobs[job_index].cooldown_time)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the `(start, duration)` pairs of every segment of the job
+    /// `job_index` in this [`JobSchedule`], in timetable order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobSchedule};
+    /// let schedule = JobSchedule::new(
+    ///     vec![Job::new(0, 4, 1), Job::new(2, 2, 10)],
+    ///     vec![(0, 0, 2), (2, 1, 2), (4, 0, 2)],
+    /// );
+    /// assert_eq!(schedule.segments_for_job(0), vec![(0, 2), (4, 2)]);
+    /// assert_eq!(schedule.start_time(0), Some(0));
+    /// assert_eq!(schedule.completion_time(0), 6);
+    /// ```
+    pub fn segments_for_job(&self, job_index: usize) -> Vec<(u32, u32)> {
+        self.timetable
+            .iter()
+            .filter(|&&(_, j, _)| j == job_index)
+            .map(|&(start, _, duration)| (start, duration))
+            .collect()
+    }
+
+    /// Returns the time at which the last segment of the job `job_index`
+    /// ends, 0 if the job is not in the timetable.
+    pub fn completion_time(&self, job_index: usize) -> u32 {
+        self.segments_for_job(job_index)
+            .iter()
+            .map(|&(start, duration)| start + duration)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the time at which the first segment of the job `job_index`
+    /// starts, `None` if the job is not in the timetable.
+    pub fn start_time(&self, job_index: usize) -> Option<u32> {
+        self.segments_for_job(job_index)
+            .iter()
+            .map(|&(start, _)| start)
+            .min()
+    }
+
+    /// Returns the total time the machine is idle between consecutive
+    /// timetable entries, 0 if the timetable is empty.
+    pub fn total_idle_time(&self) -> u32 {
+        self.timetable
+            .windows(2)
+            .map(|w| w[1].0.saturating_sub(w[0].0 + w[0].2))
+            .sum()
+    }
+
+    /// Returns how many times a job is interrupted before it is done, i.e.
+    /// the number of timetable entries followed by a later entry
+    /// of the same job. Returns 0 if the timetable is empty.
+    pub fn number_of_preemptions(&self) -> usize {
+        let mut segments = vec![0; self.jobs.len()];
+        for &(_, job_index, _) in &self.timetable {
+            segments[job_index] += 1;
+        }
+        segments.iter().map(|&n: &usize| n.saturating_sub(1)).sum()
+    }
+
+    /// Returns whether every job of this [`JobSchedule`] runs in exactly one
+    /// timetable entry, i.e. no job is preempted.
+    pub fn is_non_preemptive(&self) -> bool {
+        let mut segments = vec![0; self.jobs.len()];
+        for &(_, job_index, _) in &self.timetable {
+            segments[job_index] += 1;
+        }
+        segments.iter().all(|&n: &usize| n == 1)
+    }
+
+    /// Returns the jobs of this [`JobSchedule`] as a [`JobList`] in the order
+    /// they run, or `None` if any job is preempted.
+    ///
+    /// A [`JobList`] starts every job as early as it can, so its c max is the
+    /// same as the one of the schedule unless the schedule leaves the machine
+    /// idle while a job is ready, then it can only be lower.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobSchedule};
+    /// let jobs = vec![Job::new(0, 4, 1), Job::new(2, 2, 10)];
+    /// let schedule = JobSchedule::new(jobs.clone(), vec![(0, 0, 4), (4, 1, 2)]);
+    /// let list = schedule.to_non_preemptive().unwrap();
+    /// assert_eq!(list.jobs, jobs);
+    /// assert_eq!(list.c_max(), schedule.c_max());
+    /// let preempted = JobSchedule::new(jobs, vec![(0, 0, 2), (2, 1, 2), (4, 0, 2)]);
+    /// assert_eq!(preempted.to_non_preemptive(), None);
+    /// ```
+    pub fn to_non_preemptive(&self) -> Option<JobList> {
+        if !self.is_non_preemptive() {
+            return None;
+        }
+        let mut timetable = self.timetable.clone();
+        timetable.sort_by_key(|&(start, _, _)| start);
+        Some(
+            timetable
+                .iter()
+                .map(|&(_, job_index, _)| self.jobs[job_index])
+                .collect(),
+        )
+    }
+
+    /// Returns whether this [`JobSchedule`] is a valid schedule of its jobs.
+    ///
+    /// It is if every timetable entry refers to one of the jobs, no segment
+    /// starts before the delivery time of its job, the segments of every job
+    /// add up to its processing time and no two segments overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_opt::schrage::jobs::{Job, JobSchedule};
+    /// let jobs = vec![Job::new(0, 4, 1), Job::new(2, 2, 10)];
+    /// let schedule = JobSchedule::new(jobs.clone(), vec![(0, 0, 2), (2, 1, 2), (4, 0, 2)]);
+    /// assert!(schedule.is_feasible());
+    /// let overlapping = JobSchedule::new(jobs, vec![(0, 0, 4), (2, 1, 2)]);
+    /// assert!(!overlapping.is_feasible());
+    /// ```
+    pub fn is_feasible(&self) -> bool {
+        let mut allocated = vec![0; self.jobs.len()];
+        for &(start, job_index, duration) in &self.timetable {
+            match self.jobs.get(job_index) {
+                Some(job) if start >= job.delivery_time => allocated[job_index] += duration,
+                _ => return false,
             }
-            end_times[i] = s;
+        }
+        if self
+            .jobs
+            .iter()
+            .zip(&allocated)
+            .any(|(job, &time)| time != job.processing_time)
+        {
+            return false;
         }
 
-        for (i, job) in self.job_list.jobs.iter().enumerate() {
-            sums[i] = job.cooldown_time + end_times[i];
+        let mut segments: Vec<(u32, u32)> = self
+            .timetable
+            .iter()
+            .map(|&(start, _, duration)| (start, start + duration))
+            .collect();
+        segments.sort_unstable();
+        segments.windows(2).all(|w| w[0].1 <= w[1].0)
+    }
+
+    /// Returns the c max of this [`JobSchedule`] assuming that preempted jobs
+    /// lose their progress and have to restart from scratch.
+    ///
+    /// Segments are kept in the timetable order, every segment but the last
+    /// one of a job is wasted time and the last one runs the whole job.
+    ///
+    /// Returns 0 if the timetable is empty.
+    pub fn non_resumable_c_max(&self) -> u32 {
+        let mut last_segment = vec![0; self.jobs.len()];
+        for (i, &(_, job_index, _)) in self.timetable.iter().enumerate() {
+            last_segment[job_index] = i;
         }
-        *sums.iter().max().unwrap()
+
+        let mut t = 0;
+        let mut c_max = 0;
+        for (i, &(start, job_index, duration)) in self.timetable.iter().enumerate() {
+            let job = &self.jobs[job_index];
+            t = cmp::max(t, start);
+            if last_segment[job_index] == i {
+                t += job.processing_time;
+                c_max = cmp::max(c_max, t + job.cooldown_time);
+            } else {
+                t += duration;
+            }
+        }
+        c_max
     }
 }
 
@@ -267,4 +1113,575 @@ mod tests {
         let result = js.c_max();
         assert_eq!(result, 1399);
     }
+
+    #[test]
+    fn test_remove_dominated() {
+        let js = JobList::new(vec![
+            Job::new(3, 5, 25), // 1
+            Job::new(5, 2, 1),  // 2, dominated by 4
+            Job::new(1, 6, 15), // 3
+            Job::new(0, 4, 20), // 4
+        ]);
+        let reduced = js.remove_dominated();
+        assert_eq!(
+            reduced.jobs,
+            vec![Job::new(0, 4, 20), Job::new(1, 6, 15), Job::new(3, 5, 25)]
+        );
+        // The dominated job runs while the others cool down
+        let schrage_c_max = crate::schrage::schrage(&js).c_max();
+        assert_eq!(schrage_c_max, 34);
+        assert_eq!(crate::schrage::schrage(&reduced).c_max(), schrage_c_max);
+        assert_eq!(reduced.c_max_lower_bound(), js.c_max_lower_bound());
+        assert_eq!(reduced.c_max_lower_bound_2(), js.c_max_lower_bound_2());
+        assert_eq!(
+            crate::exact::branch_and_bound(reduced.jobs).c_max(),
+            crate::exact::branch_and_bound(js.jobs.clone()).c_max()
+        );
+
+        // Only one of equal jobs is kept
+        let twice = JobList::new(vec![Job::new(0, 2, 3), Job::new(0, 2, 3)]);
+        assert_eq!(twice.remove_dominated().jobs, vec![Job::new(0, 2, 3)]);
+        assert!(JobList::new(Vec::new()).remove_dominated().jobs.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_and_scale() {
+        let js = JobList::new(vec![
+            Job::new(1010, 5, 7),  // 1
+            Job::new(1013, 6, 26), // 2
+            Job::new(1011, 7, 24), // 3
+            Job::new(1020, 4, 21), // 4
+            Job::new(1030, 3, 8),  // 5
+            Job::new(1000, 6, 17), // 6
+            Job::new(1030, 2, 0),  // 7
+        ]);
+        let normalized = js.normalize();
+        assert_eq!(normalized.jobs.len(), js.jobs.len());
+        assert_eq!(normalized.jobs[5], Job::new(0, 6, 17));
+        assert_eq!(normalized.c_max(), js.c_max() - 1000);
+        assert_eq!(normalized.normalize(), normalized);
+        for (a, b) in normalized.iter().zip(&js) {
+            assert_eq!(a.delivery_time + 1000, b.delivery_time);
+        }
+
+        let scaled = normalized.scale(3);
+        assert_eq!(scaled.jobs.len(), js.jobs.len());
+        assert_eq!(scaled.jobs[0], Job::new(30, 15, 21));
+        assert_eq!(scaled.c_max(), normalized.c_max() * 3);
+        assert_eq!(
+            JobList::new(vec![Job::new(1, 2, 3).with_due_date(4).with_weight(5)])
+                .scale(2)
+                .jobs,
+            vec![Job::new(2, 4, 6).with_due_date(8).with_weight(5)]
+        );
+        assert!(JobList::new(Vec::new()).normalize().jobs.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_overflow() {
+        JobList::new(vec![Job::new(0, u32::MAX / 2 + 1, 0)]).scale(2);
+    }
+
+    #[test]
+    fn test_c_max_lower_bounds() {
+        let js = JobList::new(vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ]);
+        assert_eq!(js.c_max_lower_bound(), 45);
+        // The optimum is 50
+        assert_eq!(js.c_max_lower_bound_2(), 49);
+
+        let js = JobList::new(vec![
+            Job::new(1, 5, 9), // 1
+            Job::new(4, 5, 4), // 2
+            Job::new(1, 4, 6), // 3
+            Job::new(7, 3, 3), // 4
+            Job::new(3, 6, 8), // 5
+            Job::new(4, 7, 1), // 6
+        ]);
+        assert_eq!(js.c_max_lower_bound(), 17);
+        // Schrage is optimal here
+        assert_eq!(js.c_max_lower_bound_2(), 32);
+
+        let empty = JobList::new(Vec::new());
+        assert_eq!(empty.c_max_lower_bound(), 0);
+        assert_eq!(empty.c_max_lower_bound_2(), 0);
+    }
+
+    #[test]
+    fn test_optimality_gap_and_dominates() {
+        let schrage_result = JobList::new(vec![
+            Job::new(0, 6, 17),  // 6
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(30, 2, 0),  // 7
+        ]);
+        let optimal = JobList::new(vec![
+            Job::new(0, 6, 17),  // 6
+            Job::new(11, 7, 24), // 3
+            Job::new(13, 6, 26), // 2
+            Job::new(20, 4, 21), // 4
+            Job::new(10, 5, 7),  // 1
+            Job::new(30, 3, 8),  // 5
+            Job::new(30, 2, 0),  // 7
+        ]);
+        assert_eq!(schrage_result.c_max(), 53);
+        assert_eq!(optimal.c_max(), 50);
+        assert!((schrage_result.optimality_gap(&optimal) - 6.0).abs() < 1e-9);
+        assert!(optimal.optimality_gap(&schrage_result) < 0.0);
+        assert_eq!(optimal.optimality_gap(&optimal), 0.0);
+        assert!(optimal.dominates(&schrage_result));
+        assert!(optimal.dominates(&optimal));
+        assert!(!schrage_result.dominates(&optimal));
+    }
+
+    #[test]
+    fn test_filter_by_delivery_partition() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        for t in [0, 10, 12, 29, 30, 100] {
+            let before: Vec<&Job> = filter_by_delivery_before(&jobs, t).collect();
+            let after: Vec<&Job> = filter_by_delivery_after(&jobs, t).collect();
+            assert!(before.iter().all(|j| j.delivery_time <= t));
+            assert!(after.iter().all(|j| j.delivery_time > t));
+            assert_eq!(before.len() + after.len(), jobs.len());
+            for job in &jobs {
+                assert!(before.contains(&job) != after.contains(&job));
+            }
+        }
+        assert_eq!(filter_by_delivery_before(&jobs, 30).count(), 7);
+        assert_eq!(filter_by_delivery_after(&[], 0).count(), 0);
+    }
+
+    #[test]
+    fn test_merge_by_delivery_time() {
+        let lists = vec![
+            JobList::new(vec![
+                Job::new(10, 5, 7),  // 1
+                Job::new(13, 6, 26), // 2
+                Job::new(11, 7, 24), // 3
+            ]),
+            JobList::new(Vec::new()),
+            JobList::new(vec![
+                Job::new(20, 4, 21), // 4
+                Job::new(30, 3, 8),  // 5
+            ]),
+            JobList::new(vec![
+                Job::new(0, 6, 17), // 6
+                Job::new(30, 2, 0), // 7
+            ]),
+        ];
+        let flat: Vec<Job> = lists.iter().flat_map(|list| list.jobs.clone()).collect();
+        let merged = merge_by_delivery_time(lists);
+        assert_eq!(merged.jobs.len(), flat.len());
+        assert!(merged
+            .jobs
+            .windows(2)
+            .all(|w| w[0].delivery_time <= w[1].delivery_time));
+        for job in &flat {
+            assert!(merged.jobs.contains(job));
+        }
+        assert_eq!(
+            crate::schrage::schrage(&merged).job_list,
+            crate::schrage::schrage(&JobList::new(flat)).job_list
+        );
+        assert!(merge_by_delivery_time(Vec::new()).jobs.is_empty());
+    }
+
+    #[test]
+    fn test_group_by_delivery() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let groups = group_by_delivery(&jobs);
+        let delivery_times: Vec<u32> = groups.iter().map(|(t, _)| *t).collect();
+        assert_eq!(delivery_times, vec![0, 10, 11, 13, 20, 30]);
+        assert_eq!(groups[5].1, vec![&jobs[4], &jobs[6]]);
+        for (t, group) in &groups {
+            assert!(group.iter().all(|j| j.delivery_time == *t));
+        }
+        let grouped: Vec<&Job> = groups.iter().flat_map(|(_, group)| group.clone()).collect();
+        assert_eq!(grouped.len(), jobs.len());
+        for job in &jobs {
+            assert!(grouped.iter().any(|j| std::ptr::eq(*j, job)));
+        }
+        assert!(group_by_delivery(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_earliest_starts() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        for machine_available_at in [0, 12, 50] {
+            let result = earliest_starts(&jobs, machine_available_at);
+            assert_eq!(result.len(), jobs.len());
+            assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+            for &(i, start) in &result {
+                assert!(start >= machine_available_at);
+                assert!(start >= jobs[i].delivery_time);
+            }
+        }
+        assert_eq!(
+            earliest_starts(&jobs, 12),
+            vec![
+                (0, 12),
+                (2, 12),
+                (5, 12),
+                (1, 13),
+                (3, 20),
+                (4, 30),
+                (6, 30)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_job_list_iterators() {
+        let jobs = JobList::new(vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+        ]);
+        assert_eq!(jobs.iter().cloned().collect::<JobList>(), jobs);
+        assert_eq!(jobs.clone().into_iter().collect::<JobList>(), jobs);
+        assert_eq!((&jobs).into_iter().count(), 3);
+
+        let long: JobList = jobs
+            .clone()
+            .into_iter()
+            .filter(|j| j.processing_time > 5)
+            .collect();
+        assert_eq!(long.jobs, vec![Job::new(13, 6, 26), Job::new(11, 7, 24)]);
+
+        let mut extended = long;
+        extended.extend(vec![Job::new(0, 1, 2)]);
+        assert_eq!(extended.jobs.len(), 3);
+
+        let mut shifted = jobs.clone();
+        for job in shifted.iter_mut() {
+            job.delivery_time += 1;
+        }
+        assert_eq!(shifted.jobs[0], Job::new(11, 5, 7));
+    }
+
+    #[test]
+    fn test_job_list_conversions() {
+        let jobs = vec![Job::new(10, 5, 7), Job::new(13, 6, 26)];
+        let job_list: JobList = jobs.clone().into();
+        assert_eq!(job_list, JobList::new(jobs.clone()));
+        assert_eq!(JobList::from(jobs.clone()).c_max(), 47);
+        let back: Vec<Job> = job_list.into();
+        assert_eq!(back, jobs);
+        assert!(Vec::<Job>::from(JobList::new(Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn test_labeled_job() {
+        let labeled = vec![
+            LabeledJob::new("Job A", 10, 5, 7),
+            LabeledJob::from(Job::new(13, 6, 26)),
+            LabeledJob::new("Delivery run", 11, 7, 24),
+        ];
+        assert_eq!(
+            labels(&labeled),
+            vec![Some("Job A"), None, Some("Delivery run")]
+        );
+        assert_eq!(labeled[0].to_string(), "Job A (10, 5, 7)");
+        assert_eq!(labeled[1].to_string(), Job::new(13, 6, 26).to_string());
+
+        // The labels do not change the schedule
+        let jobs: JobList = labeled.into_iter().map(LabeledJob::into_job).collect();
+        let unlabeled = JobList::new(vec![
+            Job::new(10, 5, 7),
+            Job::new(13, 6, 26),
+            Job::new(11, 7, 24),
+        ]);
+        assert_eq!(jobs, unlabeled);
+        assert_eq!(schrage_preemptive(&jobs), schrage_preemptive(&unlabeled));
+    }
+
+    #[test]
+    fn test_completion_and_flow_times() {
+        let js = JobList::new(vec![
+            Job::new(0, 6, 17),  // 6
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(30, 2, 0),  // 7
+        ]);
+        let completion_times = js.completion_times();
+        assert_eq!(completion_times, vec![6, 15, 21, 28, 32, 35, 37]);
+        let c_max = js
+            .iter()
+            .zip(&completion_times)
+            .map(|(job, c)| c + job.cooldown_time)
+            .max();
+        assert_eq!(c_max, Some(js.c_max()));
+        assert_eq!(js.flow_times(), vec![6, 5, 8, 17, 12, 5, 7]);
+        assert_eq!(js.total_flow_time(), 60);
+        assert_eq!(js.max_flow_time(), 17);
+        let total_completion_time: u32 = completion_times.iter().sum();
+        let total_delivery_time: u32 = js.iter().map(|job| job.delivery_time).sum();
+        assert_eq!(
+            js.total_flow_time(),
+            total_completion_time - total_delivery_time
+        );
+        assert_eq!(JobList::new(Vec::new()).total_flow_time(), 0);
+        assert_eq!(JobList::new(Vec::new()).max_flow_time(), 0);
+    }
+
+    #[test]
+    fn test_due_dates() {
+        let js = JobList::new(vec![
+            Job::new(0, 6, 17).with_due_date(5),   // 6
+            Job::new(10, 5, 7).with_due_date(20),  // 1
+            Job::new(13, 6, 26),                   // 2
+            Job::new(11, 7, 24).with_due_date(21), // 3
+        ]);
+        let completion_times = js.completion_times();
+        assert_eq!(completion_times, vec![6, 15, 21, 28]);
+        // Lateness 1, -5, none and 7
+        assert_eq!(js.total_lateness(&completion_times), 3);
+        assert_eq!(js.total_tardiness(&completion_times), 8);
+        assert_eq!(js.maximum_lateness(&completion_times), 7);
+        assert_eq!(js.jobs[2].due_date, None);
+        assert_eq!(js.jobs[2].tardiness(100), 0);
+
+        // Every job on time
+        let early = [5, 15, 30, 21];
+        assert!(js.total_lateness(&early) < 0);
+        assert_eq!(js.total_tardiness(&early), 0);
+        assert!(js.maximum_lateness(&early) <= 0);
+        assert!(JobList::new(Vec::new()).completion_times().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_total_tardiness_missing_completion_time() {
+        let js = JobList::new(vec![Job::new(0, 6, 17).with_due_date(5)]);
+        js.total_tardiness(&[]);
+    }
+
+    #[test]
+    fn test_job_builder() {
+        assert_eq!(Job::default(), Job::new(0, 0, 0));
+        assert_eq!(Job::default().weight, 1);
+        assert_eq!(Job::new(1, 2, 3).with_weight(4).weight, 4);
+        assert_ne!(Job::new(1, 2, 3).with_weight(4), Job::new(1, 2, 3));
+        let job = Job::default()
+            .with_delivery_time(10)
+            .with_processing_time(5)
+            .with_cooldown_time(7);
+        assert_eq!(job, Job::new(10, 5, 7));
+        assert_eq!(job.with_processing_time(1), Job::new(10, 1, 7));
+    }
+
+    #[test]
+    fn test_job_schedule_segments() {
+        let jobs = vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ];
+        let schedule = crate::schrage::schrage_preemptive(&JobList::new(jobs.clone()));
+        assert_eq!(schedule.segments_for_job(3), vec![(133, 7), (147, 69)]);
+        assert_eq!(schedule.segments_for_job(1), vec![(140, 7)]);
+        for (i, job) in jobs.iter().enumerate() {
+            let total: u32 = schedule.segments_for_job(i).iter().map(|s| s.1).sum();
+            assert_eq!(total, job.processing_time);
+        }
+        assert_eq!(schedule.start_time(3), Some(133));
+        assert_eq!(schedule.completion_time(3), 216);
+        assert_eq!(schedule.start_time(0), Some(0));
+        assert_eq!(schedule.completion_time(0), 27);
+
+        assert!(schedule.segments_for_job(4).is_empty());
+        assert_eq!(schedule.start_time(4), None);
+        assert_eq!(schedule.completion_time(4), 0);
+    }
+
+    #[test]
+    fn test_job_schedule_is_feasible() {
+        let jobs = vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ];
+        let schedule = crate::schrage::schrage_preemptive(&JobList::new(jobs.clone()));
+        assert!(schedule.is_feasible());
+        assert!(JobSchedule::new(Vec::new(), Vec::new()).is_feasible());
+
+        let timetable = vec![
+            (0, 0, 27),
+            (27, 2, 36),
+            (133, 3, 7),
+            (140, 1, 7),
+            (147, 3, 69),
+        ];
+        let with = |timetable: Vec<(u32, usize, u32)>| JobSchedule::new(jobs.clone(), timetable);
+        assert!(with(timetable.clone()).is_feasible());
+
+        // Job 3 starts before it is delivered
+        let mut early = timetable.clone();
+        early[2].0 = 132;
+        assert!(!with(early).is_feasible());
+
+        // Job 1 gets less time than it needs
+        let mut short = timetable.clone();
+        short[3].2 = 6;
+        assert!(!with(short).is_feasible());
+        // Job 2 is missing
+        let mut missing = timetable.clone();
+        missing.remove(1);
+        assert!(!with(missing).is_feasible());
+
+        // Job 2 overlaps with job 0
+        let mut overlapping = timetable.clone();
+        overlapping[1].0 = 26;
+        assert!(!with(overlapping).is_feasible());
+
+        // There is no job 4
+        let mut invalid = timetable;
+        invalid.push((300, 4, 1));
+        assert!(!with(invalid).is_feasible());
+    }
+
+    #[test]
+    fn test_job_schedule_idle_time_and_preemptions() {
+        // The schedule of test_schrage_preemptive1
+        let js = JobList::new(vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ]);
+        let result = crate::schrage::schrage_preemptive(&js);
+        // Idle from 63 to 133
+        assert_eq!(result.total_idle_time(), 70);
+        // Job 3 is preempted by job 1
+        assert_eq!(result.number_of_preemptions(), 1);
+
+        let empty = JobSchedule::new(Vec::new(), Vec::new());
+        assert_eq!(empty.total_idle_time(), 0);
+        assert_eq!(empty.number_of_preemptions(), 0);
+    }
+
+    #[test]
+    fn test_sorted_by_total_time() {
+        // Totals 9, 12, 9, 5, 12
+        let js = JobList::new(vec![
+            Job::new(1, 2, 6),
+            Job::new(0, 10, 2),
+            Job::new(4, 4, 1),
+            Job::new(0, 5, 0),
+            Job::new(7, 1, 4),
+        ]);
+        let [a, b, c, d, e] = [js.jobs[0], js.jobs[1], js.jobs[2], js.jobs[3], js.jobs[4]];
+        // Equal total times keep the input order
+        assert_eq!(js.sorted_by_total_time(), vec![d, a, c, b, e]);
+        assert_eq!(js.sorted_by_total_time_desc(), vec![b, e, a, c, d]);
+        assert_eq!(js.sorted_by_slack_time(), vec![b, e, a, c, d]);
+        assert!(JobList::new(Vec::new()).sorted_by_slack_time().is_empty());
+    }
+
+    #[test]
+    fn test_job_schedule_to_non_preemptive() {
+        // The schedule of test_schrage_preemptive1, job 3 is preempted
+        let js = JobList::new(vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ]);
+        let result = crate::schrage::schrage_preemptive(&js);
+        assert!(!result.is_non_preemptive());
+        assert_eq!(result.to_non_preemptive(), None);
+
+        // Nothing is delivered while a job runs
+        let js = JobList::new(vec![
+            Job::new(20, 5, 3),
+            Job::new(0, 6, 17),
+            Job::new(6, 4, 9),
+        ]);
+        let result = crate::schrage::schrage_preemptive(&js);
+        assert!(result.is_non_preemptive());
+        let list = result.to_non_preemptive().unwrap();
+        assert_eq!(
+            list.jobs,
+            vec![Job::new(0, 6, 17), Job::new(6, 4, 9), Job::new(20, 5, 3)]
+        );
+        assert_eq!(list.c_max(), result.c_max());
+        assert_eq!(list, crate::schrage::schrage(&js).job_list);
+
+        // A job missing from the timetable
+        let partial = JobSchedule::new(js.jobs.clone(), vec![(0, 1, 6)]);
+        assert!(!partial.is_non_preemptive());
+        assert_eq!(partial.to_non_preemptive(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let job = Job::new(10, 5, 7);
+        let json = serde_json::to_string(&job).unwrap();
+        assert_eq!(serde_json::from_str::<Job>(&json).unwrap(), job);
+        let parsed: Job = serde_json::from_str(
+            r#"{"delivery_time": 10, "processing_time": 5, "cooldown_time": 7}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed, job);
+
+        let job_list = JobList::new(vec![
+            job,
+            Job::new(0, 6, 17).with_due_date(30).with_weight(2),
+        ]);
+        let json = serde_json::to_string(&job_list).unwrap();
+        assert_eq!(serde_json::from_str::<JobList>(&json).unwrap(), job_list);
+
+        // The timetable is an array of arrays
+        let schedule = JobSchedule::new(job_list.jobs, vec![(0, 1, 6), (10, 0, 5)]);
+        let json = serde_json::to_value(&schedule).unwrap();
+        assert_eq!(
+            json["timetable"],
+            serde_json::json!([[0, 1, 6], [10, 0, 5]])
+        );
+        assert_eq!(
+            serde_json::from_value::<JobSchedule>(json).unwrap(),
+            schedule
+        );
+    }
 }
@@ -1,8 +1,79 @@
-use crate::schrage::jobs::{Job, JobList, SchrageJobTable};
-use std::{cmp, vec};
+use crate::schrage::jobs::{Job, JobList, JobSchedule, SchrageJobTable};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::{cmp, fmt};
 
+#[cfg(feature = "std")]
+pub mod analysis;
+pub mod heap;
+pub mod iter;
 pub mod jobs;
 
+/// Priority of a ready job in the Schrage algorithm.
+///
+/// Out of all the jobs that are ready to run the one with the highest
+/// priority score, i.e. the longest cooldown time, is run first.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_priority_score;
+/// assert_eq!(schrage_priority_score(&Job::new(10, 5, 7)), 7);
+/// ```
+pub fn schrage_priority_score(job: &Job) -> u32 {
+    job.cooldown_time
+}
+
+/// Tiebreaker between ready jobs with equal [`schrage_priority_score`]
+/// in the Schrage algorithm.
+///
+/// Out of the tied jobs the one with the lowest tiebreaker score,
+/// i.e. the shortest processing time, is run first.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_tiebreaker_score;
+/// assert_eq!(schrage_tiebreaker_score(&Job::new(10, 5, 7)), 5);
+/// ```
+pub fn schrage_tiebreaker_score(job: &Job) -> u32 {
+    job.processing_time
+}
+
+/// Returns the jobs in the order the Schrage algorithm picks them out of
+/// the ready jobs: by descending [`schrage_priority_score`] (cooldown time),
+/// then ascending [`schrage_tiebreaker_score`] (processing time), then in the
+/// given order.
+///
+/// If all the jobs are delivered at the same time this is the
+/// Schrage schedule.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::sort_by_schrage_priority;
+/// let jobs = vec![Job::new(0, 5, 7), Job::new(0, 3, 9), Job::new(0, 2, 7)];
+/// assert_eq!(
+///     sort_by_schrage_priority(&jobs),
+///     vec![&Job::new(0, 3, 9), &Job::new(0, 2, 7), &Job::new(0, 5, 7)]
+/// );
+/// ```
+pub fn sort_by_schrage_priority(jobs: &[Job]) -> Vec<&Job> {
+    let mut by_priority: Vec<&Job> = jobs.iter().collect();
+    by_priority.sort_by_key(|job| {
+        (
+            cmp::Reverse(schrage_priority_score(job)),
+            schrage_tiebreaker_score(job),
+        )
+    });
+    by_priority
+}
+
 /// Schrage algorithm.
 ///
 /// # Arguments
@@ -96,6 +167,475 @@ pub fn schrage(jobs: &JobList) -> SchrageJobTable {
     SchrageJobTable { job_list: pi }
 }
 
+/// Schrage algorithm returning every job with its index in `jobs`,
+/// in scheduled order.
+///
+/// The jobs are scheduled like in [`schrage`]. The preemptive timetables of
+/// [`schrage_preemptive`] already refer to the jobs by their input index.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_indexed;
+/// let jobs = vec![Job::new(10, 5, 7), Job::new(0, 6, 17)];
+/// assert_eq!(schrage_indexed(jobs.clone()), vec![(1, jobs[1]), (0, jobs[0])]);
+/// ```
+pub fn schrage_indexed(jobs: Vec<Job>) -> Vec<(usize, Job)> {
+    let js = JobList::new(jobs);
+    schrage_order(&js)
+        .into_iter()
+        .map(|i| (i, js.jobs[i]))
+        .collect()
+}
+
+/// Counters of the work done by a single run of the Schrage algorithm.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProfilingData {
+    /// Number of insertions into and removals from the queue of ready jobs.
+    pub queue_operations: usize,
+    /// Number of ready jobs compared to pick the next job to run, the queue
+    /// being scanned in full every time.
+    pub ready_jobs_scanned: usize,
+    /// Number of times the machine was idle and time skipped
+    /// to the next delivery.
+    pub idle_skips: usize,
+    /// Number of jobs scheduled.
+    pub jobs_processed: usize,
+}
+
+impl ProfilingData {
+    /// Returns a one line summary of the counters.
+    pub fn display(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ProfilingData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "jobs processed: {}, queue operations: {}, ready jobs scanned: {}, idle skips: {}",
+            self.jobs_processed, self.queue_operations, self.ready_jobs_scanned, self.idle_skips
+        )
+    }
+}
+
+/// Schrage algorithm returning the indices of the jobs in scheduled order.
+///
+/// Picks the same jobs as [`schrage`]: the ready job with the highest
+/// cooldown time, on ties the one with the shortest processing time, then
+/// the one delivered first, then the one given first.
+pub(crate) fn schrage_order(jobs: &JobList) -> Vec<usize> {
+    schrage_order_profiled(jobs, &mut ProfilingData::default())
+}
+
+/// [`schrage_order`] counting its work in `profile`.
+pub(crate) fn schrage_order_profiled(jobs: &JobList, profile: &mut ProfilingData) -> Vec<usize> {
+    let mut shortest_delivery_jobs: Vec<usize> = (0..jobs.jobs.len()).collect();
+    shortest_delivery_jobs.sort_by_key(|&i| jobs.jobs[i].delivery_time);
+    let mut next = 0;
+    let mut ready_to_run: Vec<usize> = Vec::new();
+    let mut t: u32 = 0;
+    let mut pi: Vec<usize> = Vec::new();
+
+    while next < shortest_delivery_jobs.len() || !ready_to_run.is_empty() {
+        while next < shortest_delivery_jobs.len()
+            && jobs.jobs[shortest_delivery_jobs[next]].delivery_time <= t
+        {
+            ready_to_run.push(shortest_delivery_jobs[next]);
+            profile.queue_operations += 1;
+            next += 1;
+        }
+        if ready_to_run.is_empty() {
+            t = jobs.jobs[shortest_delivery_jobs[next]].delivery_time;
+            profile.idle_skips += 1;
+            continue;
+        }
+        profile.ready_jobs_scanned += ready_to_run.len();
+        let position = (0..ready_to_run.len())
+            .max_by_key(|&k| {
+                let job = &jobs.jobs[ready_to_run[k]];
+                (
+                    schrage_priority_score(job),
+                    cmp::Reverse(schrage_tiebreaker_score(job)),
+                    cmp::Reverse(k),
+                )
+            })
+            .unwrap();
+        let i = ready_to_run.remove(position);
+        profile.queue_operations += 1;
+        // Only the order is returned, so the time may saturate
+        t = cmp::max(t, jobs.jobs[i].delivery_time).saturating_add(jobs.jobs[i].processing_time);
+        pi.push(i);
+        profile.jobs_processed += 1;
+    }
+    pi
+}
+
+/// Online Schrage algorithm, the jobs become known one at a time.
+///
+/// `arrivals` holds `(arrival_time, job)` pairs, a job is unknown to the
+/// scheduler before its arrival time, which may be later than its delivery
+/// time. Every time the machine is free the known job that is delivered with
+/// the highest cooldown time is run, with the same tie breaking as
+/// [`schrage`]. If there is none the machine waits for the next arrival or
+/// delivery. With every arrival time equal to the delivery time of its job
+/// the result is the same as [`schrage`].
+///
+/// Returns the jobs in the order they were run, note that the c max of the
+/// [`JobList`] does not account for the arrival times.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::simulate_online_schrage;
+/// // The second job is only known at 5, when the first one is already running
+/// let result = simulate_online_schrage(vec![(0, Job::new(0, 10, 1)), (5, Job::new(0, 3, 20))]);
+/// assert_eq!(result.jobs, vec![Job::new(0, 10, 1), Job::new(0, 3, 20)]);
+/// ```
+pub fn simulate_online_schrage(arrivals: Vec<(u32, Job)>) -> JobList {
+    let mut arrivals = arrivals;
+    arrivals.sort_by_key(|&(arrival_time, _)| arrival_time);
+    let mut next = 0;
+    // Jobs that arrived and are not scheduled yet, in order of arrival
+    let mut known: Vec<Job> = Vec::new();
+    let mut t: u32 = 0;
+    let mut pi = JobList::new(Vec::new());
+
+    while next < arrivals.len() || !known.is_empty() {
+        while next < arrivals.len() && arrivals[next].0 <= t {
+            known.push(arrivals[next].1);
+            next += 1;
+        }
+        let position = (0..known.len())
+            .filter(|&k| known[k].delivery_time <= t)
+            .max_by_key(|&k| {
+                (
+                    schrage_priority_score(&known[k]),
+                    cmp::Reverse(schrage_tiebreaker_score(&known[k])),
+                    cmp::Reverse(k),
+                )
+            });
+        match position {
+            Some(k) => {
+                let job = known.remove(k);
+                t += job.processing_time;
+                pi.jobs.push(job);
+            }
+            None => {
+                // Wait for the next event, an arrival or a delivery
+                let next_arrival = arrivals.get(next).map(|&(arrival_time, _)| arrival_time);
+                let next_delivery = known.iter().map(|job| job.delivery_time).min();
+                t = next_arrival.into_iter().chain(next_delivery).min().unwrap();
+            }
+        }
+    }
+    pi
+}
+
+/// Schrage algorithm where at most `resource_limit` jobs can be cooling down
+/// at the same time.
+///
+/// A job holds one unit of the resource from the moment it is done until its
+/// cooldown time is over, jobs without a cooldown time hold none. A ready
+/// job is only run if the resource is free for it when it is done, out of
+/// those the one picked by [`schrage`] is run. If no ready job can be run
+/// the machine waits until one can, or until the next job is delivered.
+///
+/// Returns the schedule of the jobs, which are kept unchanged, with the
+/// timetable in the order they were run. Its c max includes the waiting for
+/// the resource.
+///
+/// # Panics
+///
+/// Panics if `resource_limit` is 0.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_resource_constrained;
+/// // The second job can not be done before the first one cooled down at 12
+/// let jobs = vec![Job::new(0, 2, 10), Job::new(0, 2, 10)];
+/// let result = schrage_resource_constrained(jobs.clone(), 1);
+/// assert_eq!(result.jobs, jobs);
+/// assert_eq!(result.timetable, vec![(0, 0, 2), (10, 1, 2)]);
+/// assert_eq!(result.c_max(), 22);
+/// ```
+pub fn schrage_resource_constrained(jobs: Vec<Job>, resource_limit: usize) -> JobSchedule {
+    assert!(resource_limit > 0, "the resource limit must be positive");
+    let mut shortest_delivery_jobs: Vec<usize> = (0..jobs.len()).collect();
+    shortest_delivery_jobs.sort_by_key(|&i| jobs[i].delivery_time);
+    let mut next = 0;
+    let mut ready_to_run: Vec<usize> = Vec::new();
+    // Times at which the cooldowns of the jobs run so far are over
+    let mut cooldown_ends: Vec<u32> = Vec::new();
+    let mut t: u32 = 0;
+    let mut timetable = Vec::new();
+
+    while next < shortest_delivery_jobs.len() || !ready_to_run.is_empty() {
+        while next < shortest_delivery_jobs.len()
+            && jobs[shortest_delivery_jobs[next]].delivery_time <= t
+        {
+            ready_to_run.push(shortest_delivery_jobs[next]);
+            next += 1;
+        }
+        cooldown_ends.retain(|&end| end > t);
+        cooldown_ends.sort_unstable();
+        // Earliest time each ready job can start without exceeding the limit
+        let earliest_start = |job: &Job| {
+            let done = t + job.processing_time;
+            let busy = cooldown_ends.iter().filter(|&&end| end > done).count();
+            if job.cooldown_time == 0 || busy < resource_limit {
+                t
+            } else {
+                cooldown_ends[cooldown_ends.len() - resource_limit] - job.processing_time
+            }
+        };
+        let position = (0..ready_to_run.len())
+            .filter(|&k| earliest_start(&jobs[ready_to_run[k]]) == t)
+            .max_by_key(|&k| {
+                let job = &jobs[ready_to_run[k]];
+                (
+                    schrage_priority_score(job),
+                    cmp::Reverse(schrage_tiebreaker_score(job)),
+                    cmp::Reverse(k),
+                )
+            });
+        match position {
+            Some(k) => {
+                let i = ready_to_run.remove(k);
+                let job = jobs[i];
+                timetable.push((t, i, job.processing_time));
+                t += job.processing_time;
+                if job.cooldown_time > 0 {
+                    cooldown_ends.push(t + job.cooldown_time);
+                }
+            }
+            None => {
+                // Wait for the resource or the next delivery
+                let next_start = ready_to_run.iter().map(|&i| earliest_start(&jobs[i])).min();
+                let next_delivery = shortest_delivery_jobs
+                    .get(next)
+                    .map(|&i| jobs[i].delivery_time);
+                t = next_start.into_iter().chain(next_delivery).min().unwrap();
+            }
+        }
+    }
+    JobSchedule::new(jobs, timetable)
+}
+
+/// Schrage algorithm with a rolling horizon, for jobs that become known
+/// only some time before they are delivered.
+///
+/// At every planning time `T` (0, then every `step` time units) only the
+/// jobs delivered before `T + horizon` are known. They are scheduled with
+/// the Schrage algorithm after the jobs already fixed, and those of them
+/// that start before the next planning time `T + step` are fixed. The
+/// later ones are planned again with the jobs known by then. If no job is
+/// known yet, planning jumps to the next delivery, and it skips the planning
+/// times at which no job could start.
+///
+/// # Panics
+///
+/// Panics unless `0 < step <= horizon`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::rolling_horizon_schrage;
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(3, 2, 20), Job::new(4, 1, 30)];
+/// let result = rolling_horizon_schrage(jobs, 4, 2);
+/// assert_eq!(result.jobs.len(), 3);
+/// assert_eq!(result.c_max(), 36);
+/// ```
+pub fn rolling_horizon_schrage(jobs: Vec<Job>, horizon: u32, step: u32) -> JobList {
+    assert!(
+        step > 0 && step <= horizon,
+        "the step must be positive and not longer than the horizon"
+    );
+    let (horizon, step) = (horizon as u64, step as u64);
+    let mut remaining = jobs;
+    // The times are u64 as planning times and windows may go beyond u32::MAX
+    let mut machine_free: u64 = 0;
+    let mut planning_time: u64 = 0;
+    let mut pi = JobList::new(Vec::new());
+
+    while !remaining.is_empty() {
+        let window_end = planning_time + horizon;
+        let known: Vec<usize> = (0..remaining.len())
+            .filter(|&i| (remaining[i].delivery_time as u64) < window_end)
+            .collect();
+        if known.is_empty() {
+            planning_time = remaining.iter().map(|job| job.delivery_time).min().unwrap() as u64;
+            continue;
+        }
+
+        // No job, known or not, starts before the earliest start of the
+        // known ones, so the planning times until then fix nothing
+        let earliest_start = known
+            .iter()
+            .map(|&i| cmp::max(machine_free, remaining[i].delivery_time as u64))
+            .min()
+            .unwrap();
+        if earliest_start >= planning_time + step {
+            planning_time += (earliest_start - planning_time) / step * step;
+            continue;
+        }
+
+        // Nothing can start before the machine is done with the fixed jobs
+        let available: JobList = known
+            .iter()
+            .map(|&i| {
+                let job = remaining[i];
+                let machine_free = cmp::min(machine_free, u32::MAX as u64) as u32;
+                job.with_delivery_time(cmp::max(job.delivery_time, machine_free))
+            })
+            .collect();
+        let next_planning_time = planning_time + step;
+        let mut fixed = Vec::new();
+        for k in schrage_order(&available) {
+            let i = known[k];
+            let start = cmp::max(machine_free, remaining[i].delivery_time as u64);
+            if start >= next_planning_time {
+                break;
+            }
+            machine_free = start + remaining[i].processing_time as u64;
+            pi.jobs.push(remaining[i]);
+            fixed.push(i);
+        }
+        fixed.sort_unstable();
+        for i in fixed.into_iter().rev() {
+            remaining.remove(i);
+        }
+        planning_time = next_planning_time;
+    }
+    pi
+}
+
+/// Updates a schedule after the job `original_jobs[delayed_job]` turns out
+/// to be delivered `extra_delay` later than expected.
+///
+/// The jobs of `schedule` before the delayed job are assumed to be already
+/// running and keep their order. The delayed job, with its new delivery
+/// time, and the jobs after it are scheduled again with the Schrage
+/// algorithm, none of them starting before the kept jobs are done.
+///
+/// # Panics
+///
+/// Panics if `delayed_job` is out of bounds or the job is not in `schedule`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::{reoptimize_after_delay, schrage};
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(0, 2, 20)];
+/// let schedule = schrage(&JobList::new(jobs.clone())).job_list;
+/// let result = reoptimize_after_delay(&jobs, &schedule, 1, 4);
+/// assert_eq!(result.jobs, vec![Job::new(0, 5, 1), Job::new(4, 2, 20)]);
+/// assert_eq!(result.c_max(), 27);
+/// ```
+pub fn reoptimize_after_delay(
+    original_jobs: &[Job],
+    schedule: &JobList,
+    delayed_job: usize,
+    extra_delay: u32,
+) -> JobList {
+    let delayed = original_jobs[delayed_job];
+    let position = schedule
+        .jobs
+        .iter()
+        .position(|job| *job == delayed)
+        .expect("the delayed job is not in the schedule");
+
+    let kept = &schedule.jobs[..position];
+    let mut t = 0;
+    for job in kept {
+        t = cmp::max(t, job.delivery_time) + job.processing_time;
+    }
+    let mut rest = schedule.jobs[position..].to_vec();
+    rest[0].delivery_time += extra_delay;
+    // Nothing of the rest can start before the kept jobs are done
+    let available: JobList = rest
+        .iter()
+        .map(|job| job.with_delivery_time(cmp::max(job.delivery_time, t)))
+        .collect();
+
+    let mut result = JobList::new(kept.to_vec());
+    result.extend(schrage_order(&available).into_iter().map(|i| rest[i]));
+    result
+}
+
+/// Schrage algorithm on the jobs with every delivery time delayed by `r_offset`.
+///
+/// The jobs are run in the same order as without the offset and the whole
+/// schedule is shifted by it.
+///
+/// Returns the schedule of the delayed jobs and its c max, 0 for no jobs.
+///
+/// # Panics
+///
+/// Panics if a delayed delivery time does not fit into a `u32`.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_offset;
+/// let (result, c_max) = schrage_offset(vec![Job::new(0, 5, 1), Job::new(0, 2, 10)], 4);
+/// assert_eq!(result.jobs, vec![Job::new(4, 2, 10), Job::new(4, 5, 1)]);
+/// assert_eq!(c_max, 16);
+/// ```
+pub fn schrage_offset(jobs: Vec<Job>, r_offset: u32) -> (JobList, u32) {
+    let delayed: JobList = jobs
+        .into_iter()
+        .map(|job| {
+            let delivery_time = job
+                .delivery_time
+                .checked_add(r_offset)
+                .expect("delayed delivery time overflows u32");
+            job.with_delivery_time(delivery_time)
+        })
+        .collect();
+    let result = schrage(&delayed).job_list;
+    let c_max = if result.jobs.is_empty() {
+        0
+    } else {
+        result.c_max()
+    };
+    (result, c_max)
+}
+
+/// Schrage algorithm on the jobs with every processing time capped at `p_max`.
+///
+/// Shortening jobs can not lengthen the optimal schedule, so the optimum of
+/// the truncated jobs is a lower bound for the original ones, a relaxation.
+/// Schrage itself is a heuristic, on some instances the truncated schedule
+/// may still end later than the Schrage schedule of the original jobs.
+///
+/// Returns the Schrage schedule of the truncated jobs.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_truncated;
+/// let result = schrage_truncated(vec![Job::new(0, 5, 1), Job::new(0, 2, 10)], 3);
+/// assert_eq!(result.jobs, vec![Job::new(0, 2, 10), Job::new(0, 3, 1)]);
+/// assert_eq!(result.c_max(), 12);
+/// ```
+pub fn schrage_truncated(jobs: Vec<Job>, p_max: u32) -> JobList {
+    let truncated: JobList = jobs
+        .into_iter()
+        .map(|job| job.with_processing_time(cmp::min(job.processing_time, p_max)))
+        .collect();
+    schrage(&truncated).job_list
+}
+
 /// Part time Schrage algorithm.
 ///
 /// # Panics
@@ -169,9 +709,444 @@ pub fn part_time_schrage(jobs: &JobList) -> u32 {
     c_max
 }
 
+/// Preemptive Schrage algorithm building the whole timetable.
+///
+/// At any moment the ready job with the highest cooldown time is run,
+/// a running job is interrupted as soon as a job with a higher cooldown time
+/// becomes available.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::JobList;
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_preemptive;
+/// let js = JobList::new(vec![
+///     Job::new(0, 27, 78),
+///     Job::new(140, 7, 67),
+///     Job::new(14, 36, 54),
+///     Job::new(133, 76, 5),
+/// ]);
+/// let result = schrage_preemptive(&js);
+/// assert_eq!(result.c_max(), 221)
+/// ```
+pub fn schrage_preemptive(jobs: &JobList) -> JobSchedule {
+    let priorities: Vec<u32> = jobs.jobs.iter().map(schrage_priority_score).collect();
+    preemptive_by_priority(jobs, &priorities, 0..0)
+}
+
+/// Event of a preemptive schedule, see [`schrage_preemptive_with_log`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SchrageEvent {
+    /// The job is delivered.
+    JobReleased { time: u32, job: Job },
+    /// The job runs for the first time.
+    JobStarted { time: u32, job_index: usize },
+    /// The job is interrupted with `remaining` processing time left.
+    JobPreempted {
+        time: u32,
+        job_index: usize,
+        remaining: u32,
+    },
+    /// The job runs again after being preempted.
+    JobResumed { time: u32, job_index: usize },
+    /// The job is done on the machine.
+    JobCompleted { time: u32, job_index: usize },
+    /// The machine has nothing to run until `until`.
+    IdleUntil { until: u32 },
+}
+
+/// Events of a preemptive schedule in the order they happen.
+pub type EventLog = Vec<SchrageEvent>;
+
+/// Preemptive Schrage algorithm, see [`schrage_preemptive`], returning the
+/// events of the schedule along with it.
+///
+/// The events are read off the timetable, every segment is a start or
+/// resume followed by a preemption or completion. Jobs delivered at a time
+/// are logged before anything else happening at that time, so a release is
+/// logged before the preemption it causes.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::{schrage_preemptive_with_log, SchrageEvent};
+/// let (schedule, log) = schrage_preemptive_with_log(vec![Job::new(0, 5, 1), Job::new(3, 2, 10)]);
+/// assert_eq!(schedule.timetable, vec![(0, 0, 3), (3, 1, 2), (5, 0, 2)]);
+/// assert_eq!(
+///     log[3],
+///     SchrageEvent::JobPreempted { time: 3, job_index: 0, remaining: 2 }
+/// );
+/// assert_eq!(log.len(), 8);
+/// ```
+pub fn schrage_preemptive_with_log(jobs: Vec<Job>) -> (JobSchedule, EventLog) {
+    let schedule = schrage_preemptive(&JobList::new(jobs));
+    let jobs = &schedule.jobs;
+    let mut releases: Vec<usize> = (0..jobs.len()).collect();
+    releases.sort_by_key(|&i| jobs[i].delivery_time);
+    let mut releases = releases.into_iter().peekable();
+    let mut remaining: Vec<u32> = jobs.iter().map(|job| job.processing_time).collect();
+    let mut machine_free = 0;
+    let mut log = EventLog::new();
+
+    for &(start, i, duration) in &schedule.timetable {
+        let end = start + duration;
+        while let Some(j) = releases.next_if(|&j| jobs[j].delivery_time <= start) {
+            log.push(SchrageEvent::JobReleased {
+                time: jobs[j].delivery_time,
+                job: jobs[j],
+            });
+        }
+        if start > machine_free {
+            log.push(SchrageEvent::IdleUntil { until: start });
+        }
+        if remaining[i] == jobs[i].processing_time {
+            log.push(SchrageEvent::JobStarted {
+                time: start,
+                job_index: i,
+            });
+        } else {
+            log.push(SchrageEvent::JobResumed {
+                time: start,
+                job_index: i,
+            });
+        }
+        while let Some(j) = releases.next_if(|&j| jobs[j].delivery_time <= end) {
+            log.push(SchrageEvent::JobReleased {
+                time: jobs[j].delivery_time,
+                job: jobs[j],
+            });
+        }
+        remaining[i] -= duration;
+        if remaining[i] == 0 {
+            log.push(SchrageEvent::JobCompleted {
+                time: end,
+                job_index: i,
+            });
+        } else {
+            log.push(SchrageEvent::JobPreempted {
+                time: end,
+                job_index: i,
+                remaining: remaining[i],
+            });
+        }
+        machine_free = end;
+    }
+    (schedule, log)
+}
+
+/// Preemptive Schrage algorithm on a machine that is down during
+/// `[break_start, break_end)`, e.g. for planned maintenance.
+///
+/// A job running when the break starts is interrupted and resumed after it,
+/// an empty break (`break_start >= break_end`) changes nothing.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_preemptive_with_break;
+/// let result = schrage_preemptive_with_break(vec![Job::new(0, 10, 5)], 4, 8);
+/// assert_eq!(result.timetable, vec![(0, 0, 4), (8, 0, 6)]);
+/// assert_eq!(result.c_max(), 19);
+/// ```
+pub fn schrage_preemptive_with_break(
+    jobs: Vec<Job>,
+    break_start: u32,
+    break_end: u32,
+) -> JobSchedule {
+    let jobs = JobList::new(jobs);
+    let priorities: Vec<u32> = jobs.jobs.iter().map(schrage_priority_score).collect();
+    preemptive_by_priority(&jobs, &priorities, break_start..break_end)
+}
+
+/// Preemptive Schrage algorithm that interrupts jobs at most
+/// `max_preempts` times.
+///
+/// Runs like [`schrage_preemptive`] until the limit is reached. From then on
+/// the job running is finished first and the remaining jobs are run one
+/// after the other like in [`schrage`]. With `max_preempts == 0` the
+/// timetable is the Schrage schedule.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::schrage_preemptive_limited;
+/// let jobs = vec![Job::new(0, 10, 5), Job::new(2, 2, 20), Job::new(6, 2, 20)];
+/// let result = schrage_preemptive_limited(jobs.clone(), 1);
+/// assert_eq!(result.timetable, vec![(0, 0, 2), (2, 1, 2), (4, 0, 8), (12, 2, 2)]);
+/// assert_eq!(result.number_of_preemptions(), 1);
+/// assert_eq!(schrage_preemptive_limited(jobs, 0).number_of_preemptions(), 0);
+/// ```
+pub fn schrage_preemptive_limited(jobs: Vec<Job>, max_preempts: usize) -> JobSchedule {
+    // Indices of the jobs to be completed, by delivery time
+    let mut shortest_delivery_jobs: Vec<usize> = (0..jobs.len()).collect();
+    shortest_delivery_jobs.sort_by_key(|&i| jobs[i].delivery_time);
+    let mut next = 0;
+    // Indices of the jobs that in a current moment are ready to run
+    let mut ready_to_run: Vec<usize> = Vec::new();
+    // Processing time left for every job
+    let mut remaining: Vec<u32> = jobs.iter().map(|j| j.processing_time).collect();
+    let mut preempts = 0;
+    let mut t: u32 = 0;
+    let mut timetable: Vec<(u32, usize, u32)> = Vec::new();
+
+    while next < shortest_delivery_jobs.len() || !ready_to_run.is_empty() {
+        while next < shortest_delivery_jobs.len()
+            && jobs[shortest_delivery_jobs[next]].delivery_time <= t
+        {
+            ready_to_run.push(shortest_delivery_jobs[next]);
+            next += 1;
+        }
+        if ready_to_run.is_empty() {
+            t = jobs[shortest_delivery_jobs[next]].delivery_time;
+            continue;
+        }
+
+        // A job interrupted by the last delivery, if any
+        let running = timetable
+            .last()
+            .map(|&(_, j, _)| j)
+            .filter(|&j| remaining[j] > 0 && remaining[j] < jobs[j].processing_time);
+        let position = if preempts < max_preempts {
+            (0..ready_to_run.len())
+                .max_by_key(|&k| {
+                    let i = ready_to_run[k];
+                    (
+                        schrage_priority_score(&jobs[i]),
+                        running == Some(i),
+                        cmp::Reverse(k),
+                    )
+                })
+                .unwrap()
+        } else if let Some(j) = running {
+            ready_to_run.iter().position(|&i| i == j).unwrap()
+        } else {
+            (0..ready_to_run.len())
+                .max_by_key(|&k| {
+                    let i = ready_to_run[k];
+                    (
+                        schrage_priority_score(&jobs[i]),
+                        cmp::Reverse(schrage_tiebreaker_score(&jobs[i])),
+                        cmp::Reverse(k),
+                    )
+                })
+                .unwrap()
+        };
+        let i = ready_to_run[position];
+        if running.is_some_and(|j| j != i) {
+            preempts += 1;
+        }
+
+        // Run the job until it is done, or until the next job arrives
+        // while it may still be preempted
+        let mut duration = remaining[i];
+        if preempts < max_preempts && next < shortest_delivery_jobs.len() {
+            let next_delivery = jobs[shortest_delivery_jobs[next]].delivery_time;
+            duration = cmp::min(duration, next_delivery - t);
+        }
+        match timetable.last_mut() {
+            Some(last) if last.1 == i && last.0 + last.2 == t => last.2 += duration,
+            _ => timetable.push((t, i, duration)),
+        }
+        remaining[i] -= duration;
+        t += duration;
+        if remaining[i] == 0 {
+            ready_to_run.remove(position);
+        }
+    }
+    JobSchedule::new(jobs, timetable)
+}
+
+/// Returns the preemptive Schrage schedule if the machine is never idle
+/// between its first and its last job, `None` otherwise.
+///
+/// The preemptive Schrage schedule never leaves the machine idle while a
+/// job is ready, so if it has idle time every other schedule of the jobs
+/// has it as well.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::idle_free_preemptive_schedule;
+/// let schedule = idle_free_preemptive_schedule(vec![Job::new(0, 5, 1), Job::new(3, 2, 10)]);
+/// assert_eq!(schedule.unwrap().timetable, vec![(0, 0, 3), (3, 1, 2), (5, 0, 2)]);
+/// // Nothing can run between 5 and 8
+/// assert!(idle_free_preemptive_schedule(vec![Job::new(0, 5, 1), Job::new(8, 2, 10)]).is_none());
+/// ```
+pub fn idle_free_preemptive_schedule(jobs: Vec<Job>) -> Option<JobSchedule> {
+    let schedule = schrage_preemptive(&JobList::new(jobs));
+    if schedule.total_idle_time() == 0 {
+        Some(schedule)
+    } else {
+        None
+    }
+}
+
+/// C max of the preemptive Schrage algorithm computed analytically
+/// for instances where every job is delivered at time 0.
+///
+/// Without deliveries nothing is ever preempted and the jobs simply run by
+/// decreasing cooldown time, so the c max is the largest
+/// `p_1 + ... + p_k + q_k` over that order. Returns 0 if there are no jobs.
+///
+/// # Panics
+///
+/// Panics if any job has a non-zero delivery time.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::Job;
+/// use proc_opt::schrage::preemptive_c_max_zero_release;
+/// let jobs = vec![Job::new(0, 5, 1), Job::new(0, 5, 10)];
+/// assert_eq!(preemptive_c_max_zero_release(&jobs), 15);
+/// ```
+pub fn preemptive_c_max_zero_release(jobs: &[Job]) -> u32 {
+    assert!(
+        jobs.iter().all(|j| j.delivery_time == 0),
+        "all jobs must be delivered at time 0"
+    );
+    let mut by_cooldown_time = JobList::new(jobs.to_vec()).sorted_by_cooldown_time();
+    by_cooldown_time.reverse();
+    let mut t = 0;
+    let mut c_max = 0;
+    for job in by_cooldown_time {
+        t += job.processing_time;
+        c_max = cmp::max(c_max, t + job.cooldown_time);
+    }
+    c_max
+}
+
+/// Preemptive schedule running the ready job with the highest priority,
+/// the machine does nothing during `unavailable`.
+fn preemptive_by_priority(
+    jobs: &JobList,
+    priorities: &[u32],
+    unavailable: Range<u32>,
+) -> JobSchedule {
+    // N
+    // Indices of the jobs to be completed, by delivery time
+    let mut shortest_delivery_jobs: Vec<usize> = (0..jobs.jobs.len()).collect();
+    shortest_delivery_jobs.sort_by_key(|&i| jobs.jobs[i].delivery_time);
+    let mut next = 0;
+    // G
+    // Indices of the jobs that in a current moment are ready to run
+    let mut ready_to_run: Vec<usize> = Vec::new();
+    // Processing time left for every job
+    let mut remaining: Vec<u32> = jobs.jobs.iter().map(|j| j.processing_time).collect();
+    let mut t: u32 = 0;
+    let mut timetable: Vec<(u32, usize, u32)> = Vec::new();
+
+    while next < shortest_delivery_jobs.len() || !ready_to_run.is_empty() {
+        while next < shortest_delivery_jobs.len()
+            && jobs.jobs[shortest_delivery_jobs[next]].delivery_time <= t
+        {
+            ready_to_run.push(shortest_delivery_jobs[next]);
+            next += 1;
+        }
+        if unavailable.contains(&t) {
+            t = unavailable.end;
+            continue;
+        }
+        if ready_to_run.is_empty() {
+            t = jobs.jobs[shortest_delivery_jobs[next]].delivery_time;
+            continue;
+        }
+
+        // Prefer the job that is already running on equal priorities,
+        // so that it does not get preempted needlessly
+        let running = timetable.last().map(|&(_, j, _)| j);
+        let position = (0..ready_to_run.len())
+            .max_by_key(|&k| {
+                let i = ready_to_run[k];
+                (priorities[i], running == Some(i), cmp::Reverse(k))
+            })
+            .unwrap();
+        let i = ready_to_run[position];
+
+        // Run the job until it is done or until the next job arrives
+        let mut duration = remaining[i];
+        if next < shortest_delivery_jobs.len() {
+            let next_delivery = jobs.jobs[shortest_delivery_jobs[next]].delivery_time;
+            duration = cmp::min(duration, next_delivery - t);
+        }
+        if t < unavailable.start {
+            duration = cmp::min(duration, unavailable.start - t);
+        }
+        match timetable.last_mut() {
+            Some(last) if last.1 == i && last.0 + last.2 == t => last.2 += duration,
+            _ => timetable.push((t, i, duration)),
+        }
+        remaining[i] -= duration;
+        t += duration;
+        if remaining[i] == 0 {
+            ready_to_run.remove(position);
+        }
+    }
+    JobSchedule::new(jobs.jobs.clone(), timetable)
+}
+
+/// Checks whether a preemptive schedule stays within `c_max_limit`
+/// when preempted jobs cannot be resumed and have to restart from scratch.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobSchedule};
+/// use proc_opt::schrage::check_non_resumable_feasibility;
+/// let schedule = JobSchedule::new(
+///     vec![Job::new(0, 10, 15), Job::new(4, 2, 20)],
+///     vec![(0, 0, 4), (4, 1, 2), (6, 0, 6)],
+/// );
+/// assert_eq!(schedule.c_max(), 27);
+/// assert!(!check_non_resumable_feasibility(&schedule, 27));
+/// assert!(check_non_resumable_feasibility(&schedule, 31));
+/// ```
+pub fn check_non_resumable_feasibility(schedule: &JobSchedule, c_max_limit: u32) -> bool {
+    schedule.non_resumable_c_max() <= c_max_limit
+}
+
+/// Swap analysis of the preemptive Schrage algorithm.
+///
+/// For every pair of jobs `(i, j)`, `i < j`, the jobs exchange their places
+/// in the priority order of [`schrage_preemptive`] (job `i` is run as if it
+/// had the cooldown time of job `j` and the other way around) and the
+/// c max of the resulting preemptive timetable is recorded.
+///
+/// Since the preemptive Schrage schedule is optimal, no swap can give
+/// a c max below its c max.
+///
+/// # Example
+///
+/// ```
+/// use proc_opt::schrage::jobs::{Job, JobList};
+/// use proc_opt::schrage::schrage_preemptive_swap_analysis;
+/// let js = JobList::new(vec![Job::new(0, 5, 10), Job::new(0, 5, 1)]);
+/// assert_eq!(schrage_preemptive_swap_analysis(&js), vec![(0, 1, 20)]);
+/// ```
+pub fn schrage_preemptive_swap_analysis(jobs: &JobList) -> Vec<(usize, usize, u32)> {
+    let cooldown_times: Vec<u32> = jobs.jobs.iter().map(|j| j.cooldown_time).collect();
+    let mut result = Vec::new();
+    for i in 0..jobs.jobs.len() {
+        for j in i + 1..jobs.jobs.len() {
+            let mut priorities = cooldown_times.clone();
+            priorities.swap(i, j);
+            let c_max = preemptive_by_priority(jobs, &priorities, 0..0).c_max();
+            result.push((i, j, c_max));
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::rng::Rng;
     use crate::schrage::jobs::{Job, JobList};
+    use crate::test_util::random_instances;
 
     use super::*;
 
@@ -459,4 +1434,737 @@ mod tests {
         let result = part_time_schrage(&js);
         assert_eq!(result, 641);
     }
+
+    #[test]
+    fn test_simulate_online_schrage_matches_offline() {
+        let mut rng = Rng::new(4);
+        for n in 1..20 {
+            let jobs: Vec<Job> = (0..n)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 50),
+                        rng.gen_range(1, 10),
+                        rng.gen_range(0, 50),
+                    )
+                })
+                .collect();
+            let arrivals = jobs.iter().map(|&job| (job.delivery_time, job)).collect();
+            assert_eq!(
+                simulate_online_schrage(arrivals).jobs,
+                schrage(&JobList::new(jobs)).job_list.jobs
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulate_online_schrage_late_arrivals() {
+        // Offline Schrage would run the urgent job first, online it is only known at 6
+        let arrivals = vec![
+            (0, Job::new(0, 4, 1)),
+            (6, Job::new(0, 2, 30)),
+            (0, Job::new(2, 3, 5)),
+        ];
+        let result = simulate_online_schrage(arrivals);
+        assert_eq!(
+            result.jobs,
+            vec![Job::new(0, 4, 1), Job::new(2, 3, 5), Job::new(0, 2, 30)]
+        );
+        assert!(simulate_online_schrage(Vec::new()).jobs.is_empty());
+    }
+
+    /// Largest number of jobs cooling down at the same time when run in order.
+    fn max_jobs_cooling_down(schedule: &JobSchedule) -> usize {
+        let cooldowns: Vec<(u32, u32)> = schedule
+            .timetable
+            .iter()
+            .map(|&(start, i, duration)| {
+                let done = start + duration;
+                (done, done + schedule.jobs[i].cooldown_time)
+            })
+            .filter(|(start, end)| start < end)
+            .collect();
+        cooldowns
+            .iter()
+            .map(|&(start, _)| {
+                cooldowns
+                    .iter()
+                    .filter(|&&(s, e)| s <= start && start < e)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_schrage_resource_constrained_respects_limit() {
+        let mut rng = Rng::new(17);
+        for _ in 0..20 {
+            let jobs: Vec<Job> = (0..15)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 60),
+                        rng.gen_range(1, 10),
+                        rng.gen_range(0, 40),
+                    )
+                })
+                .collect();
+            for resource_limit in 1..4 {
+                let result = schrage_resource_constrained(jobs.clone(), resource_limit);
+                assert_eq!(result.jobs, jobs);
+                assert!(result.is_feasible());
+                assert!(result.is_non_preemptive());
+                assert!(max_jobs_cooling_down(&result) <= resource_limit);
+                let order = result.to_non_preemptive().unwrap();
+                assert!(analysis::jobs_to_permutation(&order, &jobs).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_schrage_resource_constrained_without_limit() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let result = schrage_resource_constrained(jobs.clone(), jobs.len());
+        let expected = schrage(&JobList::new(jobs.clone())).job_list;
+        assert_eq!(result.to_non_preemptive().unwrap(), expected);
+        assert_eq!(result.c_max(), expected.c_max());
+        // With a single resource a job waits for the cooldown before it
+        let result = schrage_resource_constrained(jobs.clone(), 1);
+        assert_eq!(result.jobs, jobs);
+        assert_eq!(max_jobs_cooling_down(&result), 1);
+        assert!(result.c_max() > 53);
+    }
+
+    #[test]
+    fn test_schrage_resource_constrained_no_cooldown() {
+        // Jobs without a cooldown time never wait
+        let jobs = vec![Job::new(0, 3, 0), Job::new(0, 2, 5), Job::new(1, 4, 0)];
+        let result = schrage_resource_constrained(jobs, 1);
+        assert_eq!(result.timetable, vec![(0, 1, 2), (2, 0, 3), (5, 2, 4)]);
+        assert!(schrage_resource_constrained(Vec::new(), 1)
+            .timetable
+            .is_empty());
+    }
+
+    #[test]
+    fn test_schrage_offset() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let original = schrage(&JobList::new(jobs.clone())).job_list;
+        for offset in [0, 1, 7, 100] {
+            let (result, c_max) = schrage_offset(jobs.clone(), offset);
+            assert_eq!(c_max, result.c_max());
+            assert_eq!(c_max, original.c_max() + offset);
+            let shifted_back: Vec<Job> = result
+                .iter()
+                .map(|job| job.with_delivery_time(job.delivery_time - offset))
+                .collect();
+            assert_eq!(shifted_back, original.jobs);
+        }
+        assert_eq!(schrage_offset(Vec::new(), 5), (JobList::new(Vec::new()), 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_schrage_offset_overflow() {
+        schrage_offset(vec![Job::new(u32::MAX - 1, 1, 0)], 2);
+    }
+
+    #[test]
+    fn test_schrage_truncated() {
+        let mut rng = Rng::new(19);
+        for _ in 0..20 {
+            let jobs: Vec<Job> = (0..12)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 60),
+                        rng.gen_range(1, 20),
+                        rng.gen_range(0, 40),
+                    )
+                })
+                .collect();
+            let original = schrage(&JobList::new(jobs.clone())).job_list;
+            assert_eq!(schrage_truncated(jobs.clone(), u32::MAX), original);
+            for p_max in [1, 5, 10, 15] {
+                let result = schrage_truncated(jobs.clone(), p_max);
+                assert_eq!(result.jobs.len(), jobs.len());
+                assert!(result.iter().all(|job| job.processing_time <= p_max));
+                // The relaxation can only lower the optimum and its bound
+                let truncated_bound = schrage_preemptive(&result).c_max();
+                assert!(truncated_bound <= schrage_preemptive(&original).c_max());
+                assert!(result.c_max() >= truncated_bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_schrage_truncated_ex1() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let mut previous = schrage(&JobList::new(jobs.clone())).c_max();
+        assert_eq!(previous, 53);
+        for p_max in (1..7).rev() {
+            let c_max = schrage_truncated(jobs.clone(), p_max).c_max();
+            assert!(c_max <= previous);
+            previous = c_max;
+        }
+    }
+
+    #[test]
+    fn test_reoptimize_after_delay() {
+        let jobs = vec![
+            Job::new(10, 5, 7),  // 1
+            Job::new(13, 6, 26), // 2
+            Job::new(11, 7, 24), // 3
+            Job::new(20, 4, 21), // 4
+            Job::new(30, 3, 8),  // 5
+            Job::new(0, 6, 17),  // 6
+            Job::new(30, 2, 0),  // 7
+        ];
+        let schedule = schrage(&JobList::new(jobs.clone())).job_list;
+        let lower_bound = schrage_preemptive(&schedule).c_max();
+        for delayed_job in 0..jobs.len() {
+            for extra_delay in [1, 5, 20] {
+                let result = reoptimize_after_delay(&jobs, &schedule, delayed_job, extra_delay);
+                assert_eq!(result.jobs.len(), jobs.len());
+                // The jobs before the delayed one are kept
+                let position = schedule
+                    .jobs
+                    .iter()
+                    .position(|j| *j == jobs[delayed_job])
+                    .unwrap();
+                assert_eq!(result.jobs[..position], schedule.jobs[..position]);
+                // Schrage is a heuristic, a delay can even lead it to a
+                // better schedule, but never below the preemptive bound
+                assert!(result.c_max() >= lower_bound);
+
+                // The delayed job does not start before its new delivery time
+                let new_delivery_time = jobs[delayed_job].delivery_time + extra_delay;
+                let mut t = 0;
+                let mut found = false;
+                for job in &result.jobs {
+                    let start = cmp::max(t, job.delivery_time);
+                    if *job == jobs[delayed_job].with_delivery_time(new_delivery_time) {
+                        assert!(start >= new_delivery_time);
+                        found = true;
+                    }
+                    t = start + job.processing_time;
+                }
+                assert!(found);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reoptimize_after_delay_grows_c_max() {
+        let jobs = vec![
+            Job::new(52, 1, 56),   // 1
+            Job::new(70, 4, 93),   // 2
+            Job::new(112, 22, 79), // 3
+            Job::new(5, 14, 125),  // 4
+            Job::new(8, 16, 114),  // 5
+            Job::new(71, 7, 71),   // 6
+            Job::new(90, 2, 13),   // 7
+            Job::new(2, 20, 88),   // 8
+            Job::new(52, 20, 56),  // 9
+            Job::new(9, 28, 94),   // 10
+        ];
+        let schedule = schrage(&JobList::new(jobs.clone())).job_list;
+        // Schrage is optimal here (213), so any delay can only make it worse
+        for delayed_job in 0..jobs.len() {
+            let result = reoptimize_after_delay(&jobs, &schedule, delayed_job, 30);
+            assert!(result.c_max() >= 213);
+        }
+        // Delaying the first job delays everything
+        let result = reoptimize_after_delay(&jobs, &schedule, 7, 30);
+        assert!(result.c_max() > 213);
+    }
+
+    #[test]
+    fn test_sort_by_schrage_priority_simultaneous_arrivals() {
+        let mut rng = Rng::new(6);
+        for n in 1..20 {
+            let delivery_time = rng.gen_range(0, 10);
+            let jobs: Vec<Job> = (0..n)
+                .map(|_| Job::new(delivery_time, rng.gen_range(1, 5), rng.gen_range(0, 5)))
+                .collect();
+            let sorted: Vec<Job> = sort_by_schrage_priority(&jobs)
+                .into_iter()
+                .copied()
+                .collect();
+            assert_eq!(sorted, schrage(&JobList::new(jobs)).job_list.jobs);
+        }
+    }
+
+    #[test]
+    fn test_schrage_preemptive_with_break() {
+        let jobs = vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ];
+        let result = schrage_preemptive_with_break(jobs.clone(), 20, 50);
+        // Job 1 is interrupted by the break and resumed after it
+        assert_eq!(
+            result.timetable,
+            vec![
+                (0, 0, 20),
+                (50, 0, 7),
+                (57, 2, 36),
+                (133, 3, 7),
+                (140, 1, 7),
+                (147, 3, 69)
+            ]
+        );
+        for &(start, _, duration) in &result.timetable {
+            assert!(start + duration <= 20 || start >= 50);
+        }
+        for (i, job) in jobs.iter().enumerate() {
+            let done: u32 = result
+                .timetable
+                .iter()
+                .filter(|&&(_, j, _)| j == i)
+                .map(|&(_, _, d)| d)
+                .sum();
+            assert_eq!(done, job.processing_time);
+        }
+        assert!(result.c_max() >= schrage_preemptive(&JobList::new(jobs)).c_max());
+    }
+
+    #[test]
+    fn test_schrage_preemptive_limited_extremes() {
+        for jobs in random_instances(1) {
+            let js = JobList::new(jobs.clone());
+
+            let unlimited = schrage_preemptive_limited(jobs.clone(), usize::MAX);
+            assert_eq!(unlimited, schrage_preemptive(&js));
+
+            let none = schrage_preemptive_limited(jobs.clone(), 0);
+            assert!(none.is_feasible());
+            assert_eq!(none.number_of_preemptions(), 0);
+            let order: Vec<Job> = none.timetable.iter().map(|&(_, i, _)| jobs[i]).collect();
+            assert_eq!(order, schrage(&js).job_list.jobs);
+            if !jobs.is_empty() {
+                assert_eq!(none.c_max(), schrage(&js).c_max());
+            }
+        }
+    }
+
+    #[test]
+    fn test_schrage_preemptive_limited() {
+        for jobs in random_instances(2) {
+            let lower_bound = schrage_preemptive(&JobList::new(jobs.clone())).c_max();
+            for max_preempts in 0..4 {
+                let result = schrage_preemptive_limited(jobs.clone(), max_preempts);
+                assert!(result.is_feasible());
+                assert!(result.number_of_preemptions() <= max_preempts);
+                assert!(result.c_max() >= lower_bound);
+            }
+        }
+        let jobs = vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ];
+        // The only preemption of the unlimited schedule, job 4 by job 2
+        assert_eq!(
+            schrage_preemptive_limited(jobs.clone(), 1),
+            schrage_preemptive(&JobList::new(jobs.clone()))
+        );
+        assert_eq!(
+            schrage_preemptive_limited(jobs.clone(), 0).c_max(),
+            schrage(&JobList::new(jobs)).c_max()
+        );
+    }
+
+    #[test]
+    fn test_schrage_preemptive_with_empty_break() {
+        let jobs = vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ];
+        assert_eq!(
+            schrage_preemptive_with_break(jobs.clone(), 30, 30),
+            schrage_preemptive(&JobList::new(jobs))
+        );
+    }
+
+    #[test]
+    fn test_idle_free_preemptive_schedule() {
+        let mut rng = Rng::new(23);
+        for _ in 0..10 {
+            let jobs: Vec<Job> = (0..10)
+                .map(|_| Job::new(0, rng.gen_range(1, 20), rng.gen_range(0, 40)))
+                .collect();
+            let total: u32 = jobs.iter().map(|job| job.processing_time).sum();
+            let schedule = idle_free_preemptive_schedule(jobs).unwrap();
+            assert_eq!(schedule.total_idle_time(), 0);
+            assert_eq!(schedule.number_of_preemptions(), 0);
+            let (start, _, duration) = *schedule.timetable.last().unwrap();
+            assert_eq!(start + duration, total);
+        }
+        assert!(idle_free_preemptive_schedule(Vec::new()).is_some());
+    }
+
+    #[test]
+    fn test_idle_free_preemptive_schedule_with_gap() {
+        let js = vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ];
+        // The machine waits from 63 to 133
+        assert!(idle_free_preemptive_schedule(js).is_none());
+    }
+
+    #[test]
+    fn test_schrage_preemptive1() {
+        let js = JobList::new(vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ]);
+        let result = schrage_preemptive(&js);
+        assert_eq!(
+            result.timetable,
+            vec![
+                (0, 0, 27),
+                (27, 2, 36),
+                (133, 3, 7),
+                (140, 1, 7),
+                (147, 3, 69)
+            ]
+        );
+        assert_eq!(result.c_max(), 221);
+    }
+
+    #[test]
+    fn test_schrage_preemptive2() {
+        let js = JobList::new(vec![
+            Job::new(162, 52, 241),
+            Job::new(103, 68, 470),
+            Job::new(39, 38, 340),
+            Job::new(394, 34, 400),
+            Job::new(15, 86, 700),
+            Job::new(144, 73, 536),
+            Job::new(51, 52, 403),
+            Job::new(233, 68, 23),
+            Job::new(183, 17, 641),
+            Job::new(728, 18, 640),
+            Job::new(667, 80, 92),
+            Job::new(57, 21, 76),
+            Job::new(35, 37, 386),
+            Job::new(567, 71, 618),
+            Job::new(226, 5, 629),
+            Job::new(162, 80, 575),
+            Job::new(588, 45, 632),
+            Job::new(556, 23, 79),
+            Job::new(715, 8, 93),
+            Job::new(598, 45, 200),
+        ]);
+        let result = schrage_preemptive(&js);
+        assert_eq!(result.c_max(), 1386);
+    }
+
+    #[test]
+    fn test_schrage_preemptive3() {
+        let js = JobList::new(vec![
+            Job::new(219, 5, 276),
+            Job::new(84, 13, 103),
+            Job::new(336, 35, 146),
+            Job::new(271, 62, 264),
+            Job::new(120, 33, 303),
+            Job::new(299, 14, 328),
+            Job::new(106, 46, 91),
+            Job::new(181, 93, 97),
+            Job::new(263, 13, 168),
+            Job::new(79, 60, 235),
+        ]);
+        let result = schrage_preemptive(&js);
+        assert_eq!(result.c_max(), 641);
+    }
+
+    #[test]
+    fn test_check_non_resumable_feasibility() {
+        let js = JobList::new(vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ]);
+        let result = schrage_preemptive(&js);
+        // Job 4 is preempted after 7 time units and has to run all 76 again
+        assert_eq!(result.non_resumable_c_max(), 228);
+        assert!(!check_non_resumable_feasibility(&result, 221));
+        assert!(check_non_resumable_feasibility(&result, 228));
+    }
+
+    #[test]
+    fn test_check_non_resumable_feasibility_no_preemptions() {
+        let js = JobList::new(vec![
+            Job::new(0, 6, 17),
+            Job::new(10, 5, 7),
+            Job::new(30, 2, 0),
+        ]);
+        let result = schrage_preemptive(&js);
+        assert_eq!(result.non_resumable_c_max(), result.c_max());
+        assert!(check_non_resumable_feasibility(&result, result.c_max()));
+    }
+
+    #[test]
+    fn test_schrage_preemptive_swap_analysis() {
+        let js = JobList::new(vec![
+            Job::new(0, 27, 78),
+            Job::new(140, 7, 67),
+            Job::new(14, 36, 54),
+            Job::new(133, 76, 5),
+        ]);
+        let lower_bound = schrage_preemptive(&js).c_max();
+        let result = schrage_preemptive_swap_analysis(&js);
+        assert_eq!(result.len(), 6);
+        let best = result.iter().map(|&(_, _, c_max)| c_max).min().unwrap();
+        assert!(best >= lower_bound);
+        // Jobs 1 and 2 never compete for the machine
+        assert!(result.contains(&(0, 1, lower_bound)));
+        // Job 4 would take over the machine from job 1 and delay it
+        assert!(result.contains(&(0, 3, 283)));
+    }
+
+    #[test]
+    fn test_schrage_preemptive_swap_analysis_small() {
+        let js = JobList::new(vec![Job::new(0, 1, 0)]);
+        assert!(schrage_preemptive_swap_analysis(&js).is_empty());
+    }
+
+    #[test]
+    fn test_schrage_scores_match_schrage_order() {
+        // All jobs are available at once, so Schrage runs them by the scores
+        let jobs = vec![
+            Job::new(0, 5, 10),
+            Job::new(0, 3, 10),
+            Job::new(0, 4, 12),
+            Job::new(0, 1, 2),
+            Job::new(0, 6, 10),
+        ];
+        let mut by_scores = jobs.clone();
+        by_scores.sort_by_key(|j| {
+            (
+                cmp::Reverse(schrage_priority_score(j)),
+                schrage_tiebreaker_score(j),
+            )
+        });
+        let result = schrage(&JobList::new(jobs));
+        assert_eq!(result.job_list.jobs, by_scores);
+    }
+
+    #[test]
+    fn test_preemptive_c_max_zero_release() {
+        let mut rng = Rng::new(0);
+        for n in 1..30 {
+            let jobs: Vec<Job> = (0..n)
+                .map(|_| Job::new(0, rng.gen_range(1, 100), rng.gen_range(0, 100)))
+                .collect();
+            assert_eq!(
+                preemptive_c_max_zero_release(&jobs),
+                schrage_preemptive(&JobList::new(jobs)).c_max()
+            );
+        }
+        assert_eq!(preemptive_c_max_zero_release(&[]), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_preemptive_c_max_zero_release_with_delivery() {
+        preemptive_c_max_zero_release(&[Job::new(1, 2, 3)]);
+    }
+
+    #[test]
+    fn test_schrage_indexed() {
+        for jobs in random_instances(3) {
+            let result = schrage_indexed(jobs.clone());
+            let scheduled: Vec<Job> = result.iter().map(|&(_, job)| job).collect();
+            if !jobs.is_empty() {
+                assert_eq!(
+                    scheduled,
+                    schrage(&JobList::new(jobs.clone())).job_list.jobs
+                );
+            }
+            // The indices put the input back together
+            let mut indices: Vec<usize> = result.iter().map(|&(i, _)| i).collect();
+            for &(i, job) in &result {
+                assert_eq!(jobs[i], job);
+            }
+            indices.sort_unstable();
+            assert_eq!(indices, (0..jobs.len()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_rolling_horizon_schrage() {
+        for jobs in random_instances(4) {
+            if jobs.is_empty() {
+                assert!(rolling_horizon_schrage(jobs, 10, 5).jobs.is_empty());
+                continue;
+            }
+            let js = JobList::new(jobs.clone());
+            let lower_bound = schrage_preemptive(&js).c_max();
+            for (horizon, step) in [(5, 5), (50, 10), (200, 200), (1000, 100)] {
+                let result = rolling_horizon_schrage(jobs.clone(), horizon, step);
+                assert!(analysis::jobs_to_permutation(&result, &jobs).is_some());
+                assert!(result.c_max() >= lower_bound);
+                // No job starts before it is delivered or
+                // before it is known at a planning time
+                let mut t = 0;
+                for job in &result.jobs {
+                    let start = cmp::max(t, job.delivery_time);
+                    let known_at = (job.delivery_time + 1).saturating_sub(horizon);
+                    assert!(start >= known_at);
+                    t = start + job.processing_time;
+                }
+            }
+            // Everything is known and fixed at the first planning time
+            let everything = rolling_horizon_schrage(jobs.clone(), u32::MAX, u32::MAX);
+            assert_eq!(everything, schrage(&js).job_list);
+        }
+    }
+
+    #[test]
+    fn test_rolling_horizon_schrage_late_delivery() {
+        let late = Job::new(u32::MAX, 1, 0);
+        assert_eq!(rolling_horizon_schrage(vec![late], 4, 2).jobs, vec![late]);
+
+        let jobs = vec![
+            Job::new(u32::MAX - 10, 5, 0),
+            Job::new(u32::MAX - 3, 1, 2),
+            Job::new(u32::MAX - 1, 1, 0),
+            Job::new(0, 3, 1),
+        ];
+        let result = rolling_horizon_schrage(jobs.clone(), 4, 1);
+        assert!(analysis::jobs_to_permutation(&result, &jobs).is_some());
+        assert_eq!(result.jobs[0], jobs[3]);
+
+        // The machine is busy almost until u32::MAX, with a step of 1
+        let jobs = vec![Job::new(0, u32::MAX - 5, 0), Job::new(1, 1, 0)];
+        let result = rolling_horizon_schrage(jobs.clone(), 1, 1);
+        assert_eq!(result.jobs, jobs);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rolling_horizon_schrage_step_beyond_horizon() {
+        rolling_horizon_schrage(vec![Job::new(0, 1, 1)], 5, 10);
+    }
+
+    #[test]
+    fn test_schrage_preemptive_with_log_matches_timetable() {
+        for jobs in random_instances(5) {
+            let (schedule, log) = schrage_preemptive_with_log(jobs.clone());
+            assert_eq!(schedule, schrage_preemptive(&JobList::new(jobs.clone())));
+
+            // Rebuild the timetable out of the log
+            let mut timetable = Vec::new();
+            let mut running: Option<(u32, usize)> = None;
+            let mut released = vec![false; jobs.len()];
+            let mut idle = 0;
+            let mut machine_free = 0;
+            let mut last_time = 0;
+            for event in &log {
+                let time = match *event {
+                    SchrageEvent::JobReleased { time, job } => {
+                        assert_eq!(time, job.delivery_time);
+                        let i = (0..jobs.len())
+                            .find(|&i| !released[i] && jobs[i] == job)
+                            .unwrap();
+                        released[i] = true;
+                        time
+                    }
+                    SchrageEvent::JobStarted { time, job_index }
+                    | SchrageEvent::JobResumed { time, job_index } => {
+                        assert!(running.is_none());
+                        assert!(released[job_index]);
+                        running = Some((time, job_index));
+                        time
+                    }
+                    SchrageEvent::JobPreempted {
+                        time, job_index, ..
+                    }
+                    | SchrageEvent::JobCompleted { time, job_index } => {
+                        let (start, i) = running.take().unwrap();
+                        assert_eq!(i, job_index);
+                        timetable.push((start, i, time - start));
+                        machine_free = time;
+                        time
+                    }
+                    SchrageEvent::IdleUntil { until } => {
+                        idle += until - machine_free;
+                        until
+                    }
+                };
+                assert!(time >= last_time);
+                last_time = time;
+            }
+            assert!(running.is_none());
+            assert_eq!(timetable, schedule.timetable);
+            let first_start = schedule.timetable.first().map_or(0, |&(start, _, _)| start);
+            assert_eq!(idle, schedule.total_idle_time() + first_start);
+            let preemptions = log
+                .iter()
+                .filter(|e| matches!(e, SchrageEvent::JobPreempted { .. }))
+                .count();
+            assert_eq!(preemptions, schedule.number_of_preemptions());
+            let completions = log
+                .iter()
+                .filter(|e| matches!(e, SchrageEvent::JobCompleted { .. }))
+                .count();
+            assert_eq!(completions, jobs.len());
+        }
+    }
+
+    #[test]
+    fn test_schrage_order_matches_schrage() {
+        let mut rng = Rng::new(1);
+        for n in 1..40 {
+            let js = JobList::new(
+                (0..n)
+                    .map(|_| {
+                        Job::new(
+                            rng.gen_range(0, 20),
+                            rng.gen_range(1, 5),
+                            rng.gen_range(0, 5),
+                        )
+                    })
+                    .collect(),
+            );
+            let order = schrage_order(&js);
+            let jobs: Vec<Job> = order.iter().map(|&i| js.jobs[i]).collect();
+            assert_eq!(jobs, schrage(&js).job_list.jobs);
+        }
+    }
 }
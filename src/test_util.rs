@@ -0,0 +1,119 @@
+//! Helpers shared by the tests of the crate.
+
+use crate::io::test_instances;
+use crate::rng::Rng;
+use crate::schrage::analysis::jobs_to_permutation;
+use crate::schrage::jobs::{Job, JobList};
+use crate::schrage::schrage;
+
+/// Returns the smallest value of `objective` over every order of `items`,
+/// by brute force. Only meant for a handful of items.
+pub(crate) fn min_over_permutations<T, O, F>(items: &[T], objective: F) -> O
+where
+    T: Clone,
+    O: Ord,
+    F: Fn(&[T]) -> O,
+{
+    fn permute<T, O: Ord, F: Fn(&[T]) -> O>(
+        items: &mut [T],
+        k: usize,
+        objective: &F,
+        best: &mut Option<O>,
+    ) {
+        if k == items.len() {
+            let value = objective(items);
+            if best.as_ref().is_none_or(|best| value < *best) {
+                *best = Some(value);
+            }
+            return;
+        }
+        for i in k..items.len() {
+            items.swap(k, i);
+            permute(items, k + 1, objective, best);
+            items.swap(k, i);
+        }
+    }
+    let mut best = None;
+    permute(&mut items.to_vec(), 0, &objective, &mut best);
+    // There is always at least the empty order
+    best.unwrap()
+}
+
+/// Random instances of up to 20 jobs with every time in `[0, 1000]`.
+pub(crate) fn random_instances(seed: u64) -> Vec<Vec<Job>> {
+    let mut rng = Rng::new(seed);
+    (0..200)
+        .map(|_| {
+            let n = rng.gen_index(21);
+            (0..n)
+                .map(|_| {
+                    Job::new(
+                        rng.gen_range(0, 1000),
+                        rng.gen_range(0, 1000),
+                        rng.gen_range(0, 1000),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Instances of [`test_instances`] with at least one job.
+pub(crate) fn nonempty_test_instances() -> Vec<Vec<Job>> {
+    test_instances()
+        .into_iter()
+        .filter(|jobs| !jobs.is_empty())
+        .collect()
+}
+
+/// Largest instance [`check_metaheuristic`] solves by brute force.
+const BRUTE_FORCE_MAX_JOBS: usize = 8;
+
+/// Optimal c max of `jobs`, by brute force over every order.
+pub(crate) fn brute_force_c_max(jobs: &[Job]) -> u32 {
+    min_over_permutations(jobs, |order| JobList::new(order.to_vec()).c_max())
+}
+
+/// Checks a seeded metaheuristic `solve` on every instance of
+/// [`nonempty_test_instances`]: it schedules every job once, is not worse than
+/// Schrage and gives the same schedule for the same seed. On the instances
+/// small enough for brute force it never beats the optimum and, if
+/// `finds_optimum`, the best of a few seeds reaches it.
+pub(crate) fn check_metaheuristic(solve: impl Fn(Vec<Job>, u64) -> JobList, finds_optimum: bool) {
+    for jobs in nonempty_test_instances() {
+        let schrage_c_max = schrage(&JobList::new(jobs.clone())).c_max();
+        let results: Vec<JobList> = (0..5).map(|seed| solve(jobs.clone(), seed)).collect();
+        for result in &results {
+            assert!(jobs_to_permutation(result, &jobs).is_some());
+            assert!(result.c_max() <= schrage_c_max);
+        }
+        assert_eq!(solve(jobs.clone(), 0), results[0]);
+        if jobs.len() <= BRUTE_FORCE_MAX_JOBS {
+            let optimum = brute_force_c_max(&jobs);
+            let best = results.iter().map(JobList::c_max).min();
+            assert!(results.iter().all(|result| result.c_max() >= optimum));
+            if finds_optimum {
+                assert_eq!(best, Some(optimum), "{jobs:?}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_over_permutations() {
+        // The sum of position times value is smallest by descending value
+        let weighted = |items: &[u32]| {
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| i as u32 * v)
+                .sum::<u32>()
+        };
+        assert_eq!(min_over_permutations(&[1, 3, 2], weighted), 4);
+        assert_eq!(min_over_permutations(&[] as &[u32], weighted), 0);
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "ffi")]
+
+use proc_opt::ffi::{schrage_ffi, CJob, FFI_NULL_POINTER, FFI_OK, FFI_PANIC};
+use proc_opt::schrage::jobs::{Job, JobList};
+use proc_opt::schrage::schrage;
+use std::ptr;
+
+fn c_job(r: u32, p: u32, q: u32) -> CJob {
+    CJob {
+        delivery_time: r,
+        processing_time: p,
+        cooldown_time: q,
+    }
+}
+
+fn run(jobs: &[CJob]) -> (i32, Vec<CJob>) {
+    let mut out = vec![c_job(0, 0, 0); jobs.len()];
+    let mut out_len = usize::MAX;
+    let status = unsafe { schrage_ffi(jobs.as_ptr(), jobs.len(), out.as_mut_ptr(), &mut out_len) };
+    if status == FFI_OK {
+        out.truncate(out_len);
+    }
+    (status, out)
+}
+
+#[test]
+fn test_schrage_ffi_matches_schrage() {
+    let jobs = vec![
+        c_job(10, 5, 7),
+        c_job(13, 6, 26),
+        c_job(11, 7, 24),
+        c_job(20, 4, 21),
+        c_job(30, 3, 8),
+        c_job(0, 6, 17),
+        c_job(30, 2, 0),
+    ];
+    let (status, out) = run(&jobs);
+    assert_eq!(status, FFI_OK);
+
+    let js = JobList::new(jobs.iter().map(|&job| Job::from(job)).collect());
+    let expected = schrage(&js).job_list;
+    let out = JobList::new(out.into_iter().map(Job::from).collect());
+    assert_eq!(out, expected);
+    assert_eq!(out.c_max(), 53);
+}
+
+#[test]
+fn test_schrage_ffi_empty() {
+    let mut out_len = usize::MAX;
+    let mut out = [c_job(0, 0, 0)];
+    let status = unsafe { schrage_ffi(ptr::null(), 0, out.as_mut_ptr(), &mut out_len) };
+    assert_eq!(status, FFI_OK);
+    assert_eq!(out_len, 0);
+}
+
+#[test]
+fn test_schrage_ffi_null_pointers() {
+    let jobs = [c_job(0, 1, 2)];
+    let mut out = [c_job(0, 0, 0)];
+    let mut out_len = 0;
+    unsafe {
+        assert_eq!(
+            schrage_ffi(ptr::null(), 1, out.as_mut_ptr(), &mut out_len),
+            FFI_NULL_POINTER
+        );
+        assert_eq!(
+            schrage_ffi(jobs.as_ptr(), 1, ptr::null_mut(), &mut out_len),
+            FFI_NULL_POINTER
+        );
+        assert_eq!(
+            schrage_ffi(jobs.as_ptr(), 1, out.as_mut_ptr(), ptr::null_mut()),
+            FFI_NULL_POINTER
+        );
+    }
+    // Nothing written
+    assert_eq!(out, [c_job(0, 0, 0)]);
+    assert_eq!(out_len, 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_schrage_ffi_panic() {
+    // The time overflows, which panics in debug builds
+    let jobs = [c_job(0, u32::MAX, 0), c_job(0, u32::MAX, 0)];
+    let (status, out) = run(&jobs);
+    assert_eq!(status, FFI_PANIC);
+    assert_eq!(out, vec![c_job(0, 0, 0); 2]);
+}
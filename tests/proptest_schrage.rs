@@ -0,0 +1,49 @@
+use proc_opt::schrage::jobs::{Job, JobList};
+use proc_opt::schrage::{preemptive_c_max_zero_release, schrage, schrage_preemptive};
+use proptest::prelude::*;
+
+/// Up to 20 jobs with every time in `[0, 1000]`.
+fn jobs() -> impl Strategy<Value = Vec<Job>> {
+    prop::collection::vec(
+        (0u32..=1000, 0u32..=1000, 0u32..=1000).prop_map(|(r, p, q)| Job::new(r, p, q)),
+        0..=20,
+    )
+}
+
+proptest! {
+    #[test]
+    fn test_property_preemptive_bounds_schrage(jobs in jobs()) {
+        let js = JobList::new(jobs);
+        let preemptive = schrage_preemptive(&js);
+        prop_assert!(preemptive.is_feasible());
+        if !js.jobs.is_empty() {
+            prop_assert!(preemptive.c_max() <= schrage(&js).c_max());
+        }
+    }
+
+    #[test]
+    fn test_property_schrage_above_lower_bound(jobs in jobs()) {
+        let lower_bound = jobs.iter().map(Job::total_time).max().unwrap_or(0);
+        let js = JobList::new(jobs);
+        prop_assert_eq!(js.c_max_lower_bound(), lower_bound);
+        if !js.jobs.is_empty() {
+            prop_assert!(schrage(&js).c_max() >= lower_bound);
+        }
+        prop_assert!(schrage_preemptive(&js).c_max() >= lower_bound);
+    }
+
+    #[test]
+    fn test_property_zero_delivery_times(jobs in jobs()) {
+        // Without deliveries nothing is preempted, both algorithms run the
+        // jobs by decreasing cooldown time. The c max is the largest
+        // p_1 + ... + p_k + q_k in that order, not always max(p_j + q_j).
+        let jobs: Vec<Job> = jobs.into_iter().map(|j| j.with_delivery_time(0)).collect();
+        let js = JobList::new(jobs.clone());
+        let preemptive = schrage_preemptive(&js);
+        prop_assert_eq!(preemptive.c_max(), preemptive_c_max_zero_release(&jobs));
+        prop_assert!(preemptive.c_max() >= js.c_max_lower_bound());
+        if !jobs.is_empty() {
+            prop_assert_eq!(preemptive.c_max(), schrage(&js).c_max());
+        }
+    }
+}